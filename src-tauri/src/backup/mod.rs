@@ -0,0 +1,1035 @@
+use crate::error::AppError;
+use crate::logging;
+use crate::models::{AppData, BackupSettings, BragDoc, BragEntry, Note, Task};
+use crate::storage;
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, Timelike, Utc};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use once_cell::sync::Lazy;
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::io::{Read, Write};
+use std::path::Path;
+
+// ============ Backup Names ============
+//
+// Backups are named `data-YYYY-MM-DDTHH-MM-SS[-reason].tar.gz` in local
+// time, so retention works from names alone and survives copying or touching
+// the files. Scheduled backups have no reason; snapshots taken by hand or
+// before risky operations ("manual", "pre-restore") are kept separately from
+// the tiers. Older plain `.json` backups (including `data-YYYY-MM-DD.json`,
+// read as midnight) are still listed and restorable.
+
+const NAME_PREFIX: &str = "data-";
+const ARCHIVE_SUFFIX: &str = ".tar.gz";
+const LEGACY_SUFFIX: &str = ".json";
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H-%M-%S";
+/// Snapshots (backups with a reason) kept regardless of the tiers
+const SNAPSHOTS_KEPT: usize = 10;
+
+pub const REASON_MANUAL: &str = "manual";
+pub const REASON_PRE_RESTORE: &str = "pre-restore";
+pub const REASON_CORRUPT: &str = "corrupt";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupEntry {
+    pub name: String,
+    pub created_at: NaiveDateTime,
+    pub reason: Option<String>,
+}
+
+pub fn backup_name(created_at: NaiveDateTime, reason: Option<&str>) -> String {
+    let timestamp = created_at.format(TIMESTAMP_FORMAT);
+    match reason {
+        Some(reason) => format!("{}{}-{}{}", NAME_PREFIX, timestamp, reason, ARCHIVE_SUFFIX),
+        None => format!("{}{}{}", NAME_PREFIX, timestamp, ARCHIVE_SUFFIX),
+    }
+}
+
+pub fn parse_name(name: &str) -> Option<BackupEntry> {
+    let name_stem = name.strip_prefix(NAME_PREFIX)?;
+    let stem = name_stem
+        .strip_suffix(ARCHIVE_SUFFIX)
+        .or_else(|| name_stem.strip_suffix(LEGACY_SUFFIX))?;
+
+    // Legacy daily backups
+    if let Ok(date) = NaiveDate::parse_from_str(stem, "%Y-%m-%d") {
+        return Some(BackupEntry {
+            name: name.to_string(),
+            created_at: date.and_hms_opt(0, 0, 0)?,
+            reason: None,
+        });
+    }
+
+    let timestamp = stem.get(..19)?;
+    let created_at = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT).ok()?;
+    let reason = match &stem[19..] {
+        "" => None,
+        rest => {
+            let reason = rest.strip_prefix('-')?;
+            // Reasons are ours ("pre-restore"); anything else isn't a backup name
+            if reason.is_empty() || !reason.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+                return None;
+            }
+            Some(reason.to_string())
+        }
+    };
+    Some(BackupEntry {
+        name: name.to_string(),
+        created_at,
+        reason,
+    })
+}
+
+// ============ Retention ============
+
+/// Keep the newest backup in each of the `count` most recent periods
+fn keep_tier<K: PartialEq>(
+    scheduled: &[&BackupEntry],
+    count: u32,
+    period: impl Fn(&NaiveDateTime) -> K,
+    keep: &mut HashSet<String>,
+) {
+    let mut last_period = None;
+    let mut periods = 0;
+    for entry in scheduled {
+        let current = period(&entry.created_at);
+        if last_period.as_ref() == Some(&current) {
+            continue;
+        }
+        if periods == count {
+            break;
+        }
+        keep.insert(entry.name.clone());
+        last_period = Some(current);
+        periods += 1;
+    }
+}
+
+/// Names of backups the policy no longer keeps
+pub fn to_prune(entries: &[BackupEntry], policy: &BackupSettings) -> Vec<String> {
+    let mut sorted: Vec<&BackupEntry> = entries.iter().collect();
+    sorted.sort_by_key(|e| std::cmp::Reverse(e.created_at));
+
+    let mut keep = HashSet::new();
+    // Never leave nothing to restore from
+    if let Some(newest) = sorted.first() {
+        keep.insert(newest.name.clone());
+    }
+
+    let scheduled: Vec<&BackupEntry> = sorted.iter().copied().filter(|e| e.reason.is_none()).collect();
+    keep_tier(&scheduled, policy.hourly, |t| (t.date(), t.hour()), &mut keep);
+    keep_tier(&scheduled, policy.daily, |t| t.date(), &mut keep);
+    keep_tier(&scheduled, policy.weekly, |t| t.iso_week(), &mut keep);
+
+    keep.extend(
+        sorted
+            .iter()
+            .filter(|e| e.reason.is_some())
+            .take(SNAPSHOTS_KEPT)
+            .map(|e| e.name.clone()),
+    );
+
+    sorted
+        .into_iter()
+        .filter(|e| !keep.contains(&e.name))
+        .map(|e| e.name.clone())
+        .collect()
+}
+
+// ============ Archives ============
+//
+// Each backup is a gzipped tar of `manifest.json`, `data.json` and the
+// images it references under `images/`. The manifest lists every other
+// entry with its size and SHA-256, and is checked before a restore.
+
+const MANIFEST_ENTRY: &str = "manifest.json";
+const DATA_ENTRY: &str = "data.json";
+const IMAGES_PREFIX: &str = "images/";
+const MANIFEST_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestFile {
+    pub path: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub version: u32,
+    pub app_version: String,
+    pub created_at: DateTime<Utc>,
+    pub files: Vec<ManifestFile>,
+    /// Referenced by the data but not on disk when the backup was taken
+    #[serde(default)]
+    pub missing_images: Vec<String>,
+}
+
+/// A verified backup, with image file names relative to the images dir
+pub struct BackupContents {
+    pub data: AppData,
+    pub images: Vec<(String, Vec<u8>)>,
+    /// None for legacy `.json` backups
+    pub manifest: Option<BackupManifest>,
+}
+
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn append_entry<W: Write>(builder: &mut tar::Builder<W>, path: &str, bytes: &[u8]) -> std::io::Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(bytes.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(Utc::now().timestamp().max(0) as u64);
+    header.set_cksum();
+    builder.append_data(&mut header, path, bytes)
+}
+
+/// Archive data.json and the images it references. A data.json that doesn't
+/// parse (e.g. a corrupt one being kept aside) is archived without images.
+fn write_archive(path: &Path) -> Result<(), AppError> {
+    let storage_error = |e: std::io::Error| AppError::Storage(format!("Failed to create backup: {}", e));
+
+    let data_bytes = fs::read(storage::get_data_path()).map_err(storage_error)?;
+    let referenced = serde_json::from_slice::<AppData>(&data_bytes)
+        .map(|data| storage::referenced_images(&data))
+        .unwrap_or_default();
+
+    let mut files = vec![(DATA_ENTRY.to_string(), data_bytes)];
+    let mut missing_images = Vec::new();
+    let images_dir = storage::get_images_dir();
+    for name in referenced {
+        match fs::read(images_dir.join(&name)) {
+            Ok(bytes) => files.push((format!("{}{}", IMAGES_PREFIX, name), bytes)),
+            Err(_) => missing_images.push(name),
+        }
+    }
+    if !missing_images.is_empty() {
+        logging::warn(
+            "backup",
+            &format!("Backing up without missing images: {}", missing_images.join(", ")),
+        );
+    }
+
+    let manifest = BackupManifest {
+        version: MANIFEST_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at: Utc::now(),
+        files: files
+            .iter()
+            .map(|(path, bytes)| ManifestFile {
+                path: path.clone(),
+                size: bytes.len() as u64,
+                sha256: sha256_hex(bytes),
+            })
+            .collect(),
+        missing_images,
+    };
+    let manifest_bytes = serde_json::to_vec_pretty(&manifest).map_err(|e| AppError::Internal(e.to_string()))?;
+
+    // Written beside the final name so a half-written archive is never listed
+    let temp_path = path.with_extension("tmp");
+    let file = fs::File::create(&temp_path).map_err(storage_error)?;
+    let mut builder = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+    append_entry(&mut builder, MANIFEST_ENTRY, &manifest_bytes).map_err(storage_error)?;
+    for (path, bytes) in &files {
+        append_entry(&mut builder, path, bytes).map_err(storage_error)?;
+    }
+    builder
+        .into_inner()
+        .and_then(|encoder| encoder.finish())
+        .and_then(|file| file.sync_all())
+        .map_err(storage_error)?;
+
+    fs::rename(&temp_path, path).map_err(storage_error)
+}
+
+/// Unpack an archive and check every entry against the manifest
+fn read_archive(path: &Path) -> Result<(BackupManifest, HashMap<String, Vec<u8>>), String> {
+    let file = fs::File::open(path).map_err(|e| e.to_string())?;
+    let mut archive = tar::Archive::new(GzDecoder::new(file));
+
+    let mut entries = HashMap::new();
+    for entry in archive.entries().map_err(|e| e.to_string())? {
+        let mut entry = entry.map_err(|e| e.to_string())?;
+        let entry_path = entry.path().map_err(|e| e.to_string())?.to_string_lossy().to_string();
+        let mut bytes = Vec::new();
+        entry.read_to_end(&mut bytes).map_err(|e| e.to_string())?;
+        entries.insert(entry_path, bytes);
+    }
+
+    let manifest_bytes = entries
+        .remove(MANIFEST_ENTRY)
+        .ok_or_else(|| format!("missing {}", MANIFEST_ENTRY))?;
+    let manifest: BackupManifest =
+        serde_json::from_slice(&manifest_bytes).map_err(|e| format!("invalid manifest: {}", e))?;
+    if manifest.version > MANIFEST_VERSION {
+        return Err(format!("made by a newer version (manifest v{})", manifest.version));
+    }
+
+    for file in &manifest.files {
+        let bytes = entries.get(&file.path).ok_or_else(|| format!("missing {}", file.path))?;
+        if bytes.len() as u64 != file.size || sha256_hex(bytes) != file.sha256 {
+            return Err(format!("checksum mismatch for {}", file.path));
+        }
+    }
+    if let Some(extra) = entries.keys().find(|path| !manifest.files.iter().any(|f| &f.path == *path)) {
+        return Err(format!("unexpected file {}", extra));
+    }
+    // Image entries become file names in the images dir
+    if let Some(bad) = entries
+        .keys()
+        .filter_map(|path| path.strip_prefix(IMAGES_PREFIX))
+        .find(|name| !storage::is_plain_file_name(name))
+    {
+        return Err(format!("invalid image name {}", bad));
+    }
+
+    Ok((manifest, entries))
+}
+
+// ============ Creating and Restoring ============
+
+static POLICY: Lazy<RwLock<BackupSettings>> = Lazy::new(|| RwLock::new(BackupSettings::default()));
+
+// Backups and restores touch the same files; run one at a time
+static BACKUP_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+/// Apply the backup policy from settings
+pub fn configure(settings: &BackupSettings) {
+    *POLICY.write() = settings.clone();
+}
+
+pub fn policy() -> BackupSettings {
+    POLICY.read().clone()
+}
+
+/// All backups, newest first
+pub fn list() -> Vec<BackupEntry> {
+    let mut entries: Vec<BackupEntry> = fs::read_dir(storage::get_backups_dir())
+        .map(|dir| {
+            dir.flatten()
+                .filter_map(|entry| parse_name(entry.file_name().to_str()?))
+                .collect()
+        })
+        .unwrap_or_default();
+    entries.sort_by_key(|e| std::cmp::Reverse(e.created_at));
+    entries
+}
+
+fn create_locked(reason: Option<&str>) -> Result<String, AppError> {
+    storage::ensure_directories()?;
+
+    let data_path = storage::get_data_path();
+    if !data_path.exists() {
+        return Err(AppError::NotFound("No data file to backup".to_string()));
+    }
+
+    let backup_name = backup_name(Local::now().naive_local(), reason);
+    write_archive(&storage::get_backups_dir().join(&backup_name))?;
+
+    prune_locked();
+    Ok(backup_name)
+}
+
+fn prune_locked() {
+    let policy = policy();
+    let backups_dir = storage::get_backups_dir();
+    for name in to_prune(&list(), &policy) {
+        if let Err(e) = fs::remove_file(backups_dir.join(&name)) {
+            logging::warn("backup", &format!("Failed to remove old backup '{}': {}", name, e));
+        }
+    }
+}
+
+/// Archive data.json and its images into the backups dir, then prune by the current policy.
+/// `reason` marks a snapshot, kept outside the hourly/daily/weekly tiers.
+pub fn create(reason: Option<&str>) -> Result<String, AppError> {
+    let _guard = BACKUP_LOCK.lock();
+    create_locked(reason)
+}
+
+/// Image file names a backup's data refers to, without verifying it.
+/// Archives list them in the manifest, which is read without unpacking the
+/// rest; legacy backups are parsed.
+pub fn referenced_images(backup_name: &str) -> Result<BTreeSet<String>, String> {
+    let backup_path = storage::get_backups_dir().join(backup_name);
+    if !backup_name.ends_with(ARCHIVE_SUFFIX) {
+        let contents = fs::read(&backup_path).map_err(|e| e.to_string())?;
+        let data: AppData = serde_json::from_slice(&contents).map_err(|e| e.to_string())?;
+        return Ok(storage::referenced_images(&data));
+    }
+
+    let file = fs::File::open(&backup_path).map_err(|e| e.to_string())?;
+    let mut archive = tar::Archive::new(GzDecoder::new(file));
+    let mut entry = archive
+        .entries()
+        .map_err(|e| e.to_string())?
+        .next()
+        .ok_or_else(|| "empty archive".to_string())?
+        .map_err(|e| e.to_string())?;
+    if entry.path().map_err(|e| e.to_string())?.to_str() != Some(MANIFEST_ENTRY) {
+        return Err(format!("{} is not the first entry", MANIFEST_ENTRY));
+    }
+    let mut bytes = Vec::new();
+    entry.read_to_end(&mut bytes).map_err(|e| e.to_string())?;
+    let manifest: BackupManifest = serde_json::from_slice(&bytes).map_err(|e| format!("invalid manifest: {}", e))?;
+
+    let archived = manifest
+        .files
+        .iter()
+        .filter_map(|f| Some(f.path.strip_prefix(IMAGES_PREFIX)?.to_string()));
+    Ok(archived.chain(manifest.missing_images).collect())
+}
+
+/// Read and verify a backup. Archives must match their manifest; legacy
+/// `.json` backups only have to parse.
+fn read_backup(backup_name: &str) -> Result<BackupContents, AppError> {
+    // Only names we generate, so a crafted name can't escape the backups dir
+    if parse_name(backup_name).is_none() {
+        return Err(AppError::InvalidInput(format!("Invalid backup name '{}'", backup_name)));
+    }
+
+    let backup_path = storage::get_backups_dir().join(backup_name);
+    if !backup_path.exists() {
+        return Err(AppError::NotFound(format!("Backup '{}' not found", backup_name)));
+    }
+
+    let corrupt = |detail: String| AppError::BackupInvalid(format!("'{}': {}", backup_name, detail));
+    if !backup_name.ends_with(ARCHIVE_SUFFIX) {
+        let contents = fs::read(&backup_path).map_err(|e| AppError::Storage(format!("Failed to read backup: {}", e)))?;
+        let data = serde_json::from_slice(&contents).map_err(|e| corrupt(e.to_string()))?;
+        return Ok(BackupContents {
+            data,
+            images: Vec::new(),
+            manifest: None,
+        });
+    }
+
+    let (manifest, mut files) = read_archive(&backup_path).map_err(corrupt)?;
+    let data_bytes = files
+        .remove(DATA_ENTRY)
+        .ok_or_else(|| corrupt(format!("missing {}", DATA_ENTRY)))?;
+    let data = serde_json::from_slice(&data_bytes).map_err(|e| corrupt(e.to_string()))?;
+    let images = files
+        .into_iter()
+        .filter_map(|(path, bytes)| Some((path.strip_prefix(IMAGES_PREFIX)?.to_string(), bytes)))
+        .collect();
+    Ok(BackupContents {
+        data,
+        images,
+        manifest: Some(manifest),
+    })
+}
+
+/// Put a backup's images back in the images dir, leaving ones that are
+/// already there untouched
+fn restore_images(images: &[(String, Vec<u8>)]) -> Result<(), AppError> {
+    let images_dir = storage::get_images_dir();
+    for (name, bytes) in images {
+        let path = images_dir.join(name);
+        if fs::read(&path).is_ok_and(|existing| existing == *bytes) {
+            continue;
+        }
+        fs::write(&path, bytes).map_err(|e| AppError::Storage(format!("Failed to restore image '{}': {}", name, e)))?;
+    }
+    Ok(())
+}
+
+/// Replace the current data with a backup, snapshotting the current data first
+pub fn restore(backup_name: &str) -> Result<AppData, AppError> {
+    let _guard = BACKUP_LOCK.lock();
+    // Verified before anything is touched
+    let contents = read_backup(backup_name)?;
+
+    if storage::get_data_path().exists() {
+        create_locked(Some(REASON_PRE_RESTORE))?;
+    }
+    storage::ensure_directories()?;
+    restore_images(&contents.images)?;
+    storage::save_data(&contents.data)?;
+    logging::info("backup", &format!("Restored backup '{}'", backup_name));
+    Ok(contents.data)
+}
+
+/// Recover from a corrupt data.json: keep a copy of it, then restore the
+/// newest backup that still parses
+pub fn restore_latest() -> Result<AppData, AppError> {
+    let _guard = BACKUP_LOCK.lock();
+    let corrupt = create_locked(Some(REASON_CORRUPT)).ok();
+
+    for entry in list().iter().filter(|e| Some(&e.name) != corrupt.as_ref()) {
+        match read_backup(&entry.name) {
+            Ok(BackupContents { data, images, .. }) => {
+                restore_images(&images)?;
+                storage::save_data(&data)?;
+                logging::warn("backup", &format!("Restored backup '{}'", entry.name));
+                return Ok(data);
+            }
+            Err(e) => logging::warn("backup", &format!("Skipping backup '{}': {}", entry.name, e)),
+        }
+    }
+    Err(AppError::NotFound("No backups available".to_string()))
+}
+
+// ============ Preview ============
+//
+// What restoring a backup would do to the current data: "added" items are
+// only in the backup and would come back, "removed" ones are only in the
+// current data and would be lost, and "changed" ones differ in the listed
+// top-level fields.
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ItemCounts {
+    pub tasks: usize,
+    pub notes: usize,
+    pub brag_docs: usize,
+    pub brag_entries: usize,
+    pub tags: usize,
+    pub notifications: usize,
+    pub focus_sessions: usize,
+}
+
+impl ItemCounts {
+    fn of(data: &AppData) -> Self {
+        Self {
+            tasks: data.tasks.len(),
+            notes: data.notes.len(),
+            brag_docs: data.brag_docs.len(),
+            brag_entries: data.brag_docs.iter().map(|d| d.entries.len()).sum(),
+            tags: data.tags.len(),
+            notifications: data.notifications.len(),
+            focus_sessions: data.focus_sessions.len(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ItemChange {
+    pub id: String,
+    pub title: String,
+    /// Fields that differ; empty for added and removed items
+    pub fields: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CollectionDiff {
+    pub added: Vec<ItemChange>,
+    pub removed: Vec<ItemChange>,
+    pub changed: Vec<ItemChange>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupPreview {
+    pub name: String,
+    pub created_at: NaiveDateTime,
+    pub reason: Option<String>,
+    /// False for legacy `.json` backups, which have no manifest to check
+    pub verified: bool,
+    pub images: usize,
+    pub backup: ItemCounts,
+    pub current: ItemCounts,
+    pub tasks: CollectionDiff,
+    pub notes: CollectionDiff,
+    /// Doc fields only; entry changes are listed under `brag_entries`
+    pub brag_docs: CollectionDiff,
+    pub brag_entries: CollectionDiff,
+    pub settings_changed: bool,
+}
+
+/// First line of a note, shortened, to stand in for a title
+pub fn note_title(content: &str) -> String {
+    let line = content.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("Untitled note");
+    match line.char_indices().nth(60) {
+        Some((i, _)) => format!("{}…", &line[..i]),
+        None => line.to_string(),
+    }
+}
+
+fn changed_fields<T: Serialize>(current: &T, backup: &T, ignore: &[&str]) -> Vec<String> {
+    let (Ok(serde_json::Value::Object(current)), Ok(serde_json::Value::Object(backup))) =
+        (serde_json::to_value(current), serde_json::to_value(backup))
+    else {
+        return Vec::new();
+    };
+
+    let mut fields: Vec<String> = current
+        .keys()
+        .chain(backup.keys())
+        .filter(|key| !ignore.contains(&key.as_str()) && current.get(*key) != backup.get(*key))
+        .cloned()
+        .collect();
+    fields.sort();
+    fields.dedup();
+    fields
+}
+
+fn diff_items<'a, T: Serialize + 'a>(
+    current: impl IntoIterator<Item = &'a T>,
+    backup: impl IntoIterator<Item = &'a T>,
+    id: impl Fn(&T) -> &str,
+    title: impl Fn(&T) -> String,
+    ignore: &[&str],
+) -> CollectionDiff {
+    let current: Vec<&T> = current.into_iter().collect();
+    let backup: Vec<&T> = backup.into_iter().collect();
+    let current_by_id: HashMap<&str, &T> = current.iter().map(|item| (id(item), *item)).collect();
+    let backup_by_id: HashMap<&str, &T> = backup.iter().map(|item| (id(item), *item)).collect();
+    let change = |item: &T, fields: Vec<String>| ItemChange {
+        id: id(item).to_string(),
+        title: title(item),
+        fields,
+    };
+
+    let mut diff = CollectionDiff::default();
+    for item in &backup {
+        match current_by_id.get(id(item)) {
+            None => diff.added.push(change(item, Vec::new())),
+            Some(existing) => {
+                let fields = changed_fields(*existing, *item, ignore);
+                if !fields.is_empty() {
+                    diff.changed.push(change(item, fields));
+                }
+            }
+        }
+    }
+    diff.removed = current
+        .iter()
+        .filter(|item| !backup_by_id.contains_key(id(item)))
+        .map(|item| change(item, Vec::new()))
+        .collect();
+    diff
+}
+
+/// Compare a backup's data with the current data
+pub fn diff(entry: &BackupEntry, contents: &BackupContents, current: &AppData) -> BackupPreview {
+    let backup = &contents.data;
+    BackupPreview {
+        name: entry.name.clone(),
+        created_at: entry.created_at,
+        reason: entry.reason.clone(),
+        verified: contents.manifest.is_some(),
+        images: contents.images.len(),
+        backup: ItemCounts::of(backup),
+        current: ItemCounts::of(current),
+        tasks: diff_items(&current.tasks, &backup.tasks, |t: &Task| &t.id, |t| t.title.clone(), &[]),
+        notes: diff_items(&current.notes, &backup.notes, |n: &Note| &n.id, |n| note_title(&n.content), &[]),
+        brag_docs: diff_items(&current.brag_docs, &backup.brag_docs, |d: &BragDoc| &d.id, |d| d.title.clone(), &["entries"]),
+        brag_entries: diff_items(
+            current.brag_docs.iter().flat_map(|d| &d.entries),
+            backup.brag_docs.iter().flat_map(|d| &d.entries),
+            |e: &BragEntry| &e.id,
+            |e| e.title.clone(),
+            &[],
+        ),
+        settings_changed: !changed_fields(&current.settings, &backup.settings, &[]).is_empty(),
+    }
+}
+
+/// Verify a backup and compare it with the current data, without restoring
+pub fn preview(backup_name: &str) -> Result<BackupPreview, AppError> {
+    let entry = parse_name(backup_name)
+        .ok_or_else(|| AppError::InvalidInput(format!("Invalid backup name '{}'", backup_name)))?;
+    let contents = read_backup(backup_name)?;
+    let current = storage::load_data()?;
+    Ok(diff(&entry, &contents, &current))
+}
+
+// ============ Selective Restore ============
+//
+// Individual tasks, notes, brag docs or brag entries can be copied out of a
+// backup into the current data. An item whose id is free is added as-is; if
+// the id is taken by an identical item nothing happens, otherwise the item
+// either replaces the current one or is added alongside it under a new id.
+// Tags and images the restored items refer to come back with them.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemKind {
+    Task,
+    Note,
+    BragDoc,
+    BragEntry,
+}
+
+/// How an item compares with the current data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemStatus {
+    /// Not in the current data, e.g. deleted since the backup
+    Missing,
+    Same,
+    Different,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupItem {
+    pub kind: ItemKind,
+    pub id: String,
+    pub title: String,
+    /// Date, or the brag doc an entry belongs to
+    pub detail: Option<String>,
+    pub status: ItemStatus,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ItemRef {
+    pub kind: ItemKind,
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictStrategy {
+    /// Add the backup's version under a new id, leaving the current one
+    #[default]
+    KeepBoth,
+    Replace,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RestoreOutcome {
+    Added,
+    Replaced,
+    /// Added under a new id because the original was taken
+    Copied,
+    /// Already present and identical
+    Unchanged,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RestoredItem {
+    pub kind: ItemKind,
+    pub id: String,
+    /// Id in the current data; differs from `id` when copied
+    pub restored_id: String,
+    pub outcome: RestoreOutcome,
+}
+
+fn same<T: Serialize>(a: &T, b: &T) -> bool {
+    changed_fields(a, b, &[]).is_empty()
+}
+
+fn status_of<T: Serialize>(item: &T, current: Option<&T>) -> ItemStatus {
+    match current {
+        None => ItemStatus::Missing,
+        Some(existing) if same(existing, item) => ItemStatus::Same,
+        Some(_) => ItemStatus::Different,
+    }
+}
+
+fn new_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// Items of one kind in a backup, with how each compares to the current data
+pub fn items(backup: &AppData, current: &AppData, kind: ItemKind) -> Vec<BackupItem> {
+    let item = |id: &str, title: String, detail: Option<String>, status| BackupItem {
+        kind,
+        id: id.to_string(),
+        title,
+        detail,
+        status,
+    };
+
+    match kind {
+        ItemKind::Task => backup
+            .tasks
+            .iter()
+            .map(|t| {
+                let status = status_of(t, current.tasks.iter().find(|c| c.id == t.id));
+                let detail = t.scheduled_date.map(|d| d.to_string());
+                item(&t.id, t.title.clone(), detail, status)
+            })
+            .collect(),
+        ItemKind::Note => backup
+            .notes
+            .iter()
+            .map(|n| {
+                let status = status_of(n, current.notes.iter().find(|c| c.id == n.id));
+                let detail = Some(n.updated_at.with_timezone(&Local).date_naive().to_string());
+                item(&n.id, note_title(&n.content), detail, status)
+            })
+            .collect(),
+        ItemKind::BragDoc => backup
+            .brag_docs
+            .iter()
+            .map(|d| {
+                let status = status_of(d, current.brag_docs.iter().find(|c| c.id == d.id));
+                let detail = Some(format!("{} – {}, {} entries", d.start_date, d.end_date, d.entries.len()));
+                item(&d.id, d.title.clone(), detail, status)
+            })
+            .collect(),
+        ItemKind::BragEntry => backup
+            .brag_docs
+            .iter()
+            .flat_map(|d| d.entries.iter().map(move |e| (d, e)))
+            .map(|(doc, e)| {
+                let existing = current.brag_docs.iter().flat_map(|c| &c.entries).find(|c| c.id == e.id);
+                let detail = Some(format!("{} · {}", e.date, doc.title));
+                item(&e.id, e.title.clone(), detail, status_of(e, existing))
+            })
+            .collect(),
+    }
+}
+
+/// Put `item` into `slots` following `strategy`; `set_id` renames a copy
+fn restore_into<T: Serialize + Clone>(
+    slots: &mut Vec<T>,
+    item: &T,
+    id: impl Fn(&T) -> &str,
+    set_id: impl Fn(&mut T, String),
+    strategy: ConflictStrategy,
+) -> (String, RestoreOutcome) {
+    let original_id = id(item).to_string();
+    let Some(index) = slots.iter().position(|s| id(s) == original_id) else {
+        slots.push(item.clone());
+        return (original_id, RestoreOutcome::Added);
+    };
+    if same(&slots[index], item) {
+        return (original_id, RestoreOutcome::Unchanged);
+    }
+
+    match strategy {
+        ConflictStrategy::Replace => {
+            slots[index] = item.clone();
+            (original_id, RestoreOutcome::Replaced)
+        }
+        ConflictStrategy::KeepBoth => {
+            let mut copy = item.clone();
+            let copy_id = new_id();
+            set_id(&mut copy, copy_id.clone());
+            slots.push(copy);
+            (copy_id, RestoreOutcome::Copied)
+        }
+    }
+}
+
+fn restore_entry(
+    current: &mut AppData,
+    backup_doc: &BragDoc,
+    entry: &BragEntry,
+    strategy: ConflictStrategy,
+) -> (String, RestoreOutcome) {
+    // An entry id already used in any current doc is a conflict, wherever it lives
+    let existing = current
+        .brag_docs
+        .iter()
+        .enumerate()
+        .find_map(|(d, doc)| doc.entries.iter().position(|e| e.id == entry.id).map(|i| (d, i)));
+
+    if let Some((d, i)) = existing {
+        let slot = &mut current.brag_docs[d].entries[i];
+        if same(slot, entry) {
+            return (entry.id.clone(), RestoreOutcome::Unchanged);
+        }
+        if strategy == ConflictStrategy::Replace {
+            *slot = entry.clone();
+            return (entry.id.clone(), RestoreOutcome::Replaced);
+        }
+    }
+
+    // Goes back into its original doc, recreated without its other entries if deleted
+    let doc_index = match current.brag_docs.iter().position(|d| d.id == backup_doc.id) {
+        Some(index) => index,
+        None => {
+            current.brag_docs.push(BragDoc {
+                entries: Vec::new(),
+                ..backup_doc.clone()
+            });
+            current.brag_docs.len() - 1
+        }
+    };
+
+    let mut restored = entry.clone();
+    let outcome = if existing.is_some() {
+        restored.id = new_id();
+        RestoreOutcome::Copied
+    } else {
+        RestoreOutcome::Added
+    };
+    let restored_id = restored.id.clone();
+    current.brag_docs[doc_index].entries.push(restored);
+    (restored_id, outcome)
+}
+
+/// Entry ids must stay unique across docs; drafts carried over on rollover,
+/// or a copied doc, can clash with entries elsewhere
+fn dedupe_entry_ids(current: &mut AppData, doc_id: &str) {
+    let taken: HashSet<String> = current
+        .brag_docs
+        .iter()
+        .filter(|d| d.id != doc_id)
+        .flat_map(|d| d.entries.iter().map(|e| e.id.clone()))
+        .collect();
+    if let Some(doc) = current.brag_docs.iter_mut().find(|d| d.id == doc_id) {
+        for entry in doc.entries.iter_mut().filter(|e| taken.contains(&e.id)) {
+            entry.id = new_id();
+        }
+    }
+}
+
+/// Copy the selected items from `backup` into `current`. Fails without
+/// changing anything if any item isn't in the backup.
+pub fn restore_items(
+    current: &mut AppData,
+    backup: &AppData,
+    selected: &[ItemRef],
+    strategy: ConflictStrategy,
+) -> Result<Vec<RestoredItem>, AppError> {
+    let find_entry = |id: &str| {
+        backup
+            .brag_docs
+            .iter()
+            .find_map(|d| d.entries.iter().find(|e| e.id == id).map(|e| (d, e)))
+    };
+    if let Some(missing) = selected.iter().find(|item| {
+        let id = item.id.as_str();
+        match item.kind {
+            ItemKind::Task => !backup.tasks.iter().any(|t| t.id == id),
+            ItemKind::Note => !backup.notes.iter().any(|n| n.id == id),
+            ItemKind::BragDoc => !backup.brag_docs.iter().any(|d| d.id == id),
+            ItemKind::BragEntry => find_entry(id).is_none(),
+        }
+    }) {
+        return Err(AppError::NotFound(format!("{:?} '{}' isn't in this backup", missing.kind, missing.id)));
+    }
+
+    let mut tag_ids: HashSet<String> = HashSet::new();
+    let mut restored = Vec::new();
+    for item in selected {
+        let (restored_id, outcome) = match item.kind {
+            ItemKind::Task => {
+                let Some(task) = backup.tasks.iter().find(|t| t.id == item.id) else { continue };
+                tag_ids.extend(task.tag_ids.iter().cloned());
+                restore_into(&mut current.tasks, task, |t| &t.id, |t, id| t.id = id, strategy)
+            }
+            ItemKind::Note => {
+                let Some(note) = backup.notes.iter().find(|n| n.id == item.id) else { continue };
+                tag_ids.extend(note.tag_ids.iter().cloned());
+                restore_into(&mut current.notes, note, |n| &n.id, |n, id| n.id = id, strategy)
+            }
+            ItemKind::BragDoc => {
+                let Some(doc) = backup.brag_docs.iter().find(|d| d.id == item.id) else { continue };
+                tag_ids.extend(doc.entries.iter().flat_map(|e| e.tag_ids.iter().cloned()));
+                let result = restore_into(&mut current.brag_docs, doc, |d| &d.id, |d, id| d.id = id, strategy);
+                dedupe_entry_ids(current, &result.0);
+                result
+            }
+            ItemKind::BragEntry => {
+                let Some((doc, entry)) = find_entry(&item.id) else { continue };
+                tag_ids.extend(entry.tag_ids.iter().cloned());
+                restore_entry(current, doc, entry, strategy)
+            }
+        };
+        restored.push(RestoredItem {
+            kind: item.kind,
+            id: item.id.clone(),
+            restored_id,
+            outcome,
+        });
+    }
+
+    // Bring back tags deleted since the backup
+    for tag in backup.tags.iter().filter(|t| tag_ids.contains(&t.id)) {
+        if !current.tags.iter().any(|c| c.id == tag.id) {
+            current.tags.push(tag.clone());
+        }
+    }
+    Ok(restored)
+}
+
+/// Items of one kind in a verified backup
+pub fn browse(backup_name: &str, kind: ItemKind) -> Result<Vec<BackupItem>, AppError> {
+    let contents = read_backup(backup_name)?;
+    let current = storage::load_data()?;
+    Ok(items(&contents.data, &current, kind))
+}
+
+/// Restore selected items from a backup into the current data, along with
+/// the images they use, after snapshotting the current data
+pub fn restore_selected(
+    backup_name: &str,
+    selected: &[ItemRef],
+    strategy: ConflictStrategy,
+) -> Result<Vec<RestoredItem>, AppError> {
+    // Loaded before locking: recovering a corrupt data.json takes the lock too
+    let mut current = storage::load_data()?;
+    let _guard = BACKUP_LOCK.lock();
+    let contents = read_backup(backup_name)?;
+    let restored = restore_items(&mut current, &contents.data, selected, strategy)?;
+
+    if restored.iter().all(|r| r.outcome == RestoreOutcome::Unchanged) {
+        return Ok(restored);
+    }
+    create_locked(Some(REASON_PRE_RESTORE))?;
+
+    // Only the images the current data now refers to
+    let referenced = storage::referenced_images(&current);
+    let images: Vec<(String, Vec<u8>)> = contents
+        .images
+        .into_iter()
+        .filter(|(name, _)| referenced.contains(name))
+        .collect();
+    restore_images(&images)?;
+    storage::save_data(&current)?;
+
+    logging::info(
+        "backup",
+        &format!("Restored {} items from backup '{}'", restored.len(), backup_name),
+    );
+    Ok(restored)
+}
+
+// ============ Scheduled Backups ============
+
+const SCHEDULER_TICK_SECS: u64 = 60;
+
+/// Whether a scheduled backup is due: the interval has passed since the
+/// newest backup and data.json has changed since it was taken
+pub fn is_due(
+    newest: Option<&BackupEntry>,
+    data_modified: Option<NaiveDateTime>,
+    now: NaiveDateTime,
+    interval_minutes: u32,
+) -> bool {
+    let Some(newest) = newest else {
+        return data_modified.is_some();
+    };
+    let interval_passed = now - newest.created_at >= Duration::minutes(interval_minutes.max(1) as i64);
+    let changed = data_modified.is_some_and(|m| m > newest.created_at);
+    interval_passed && changed
+}
+
+/// When data.json was last written, in local time
+pub fn data_modified() -> Option<NaiveDateTime> {
+    let modified = fs::metadata(storage::get_data_path()).ok()?.modified().ok()?;
+    Some(DateTime::<Local>::from(modified).naive_local())
+}
+
+fn run_scheduled() {
+    let _guard = BACKUP_LOCK.lock();
+    let interval = policy().interval_minutes;
+    let backups = list();
+    if !is_due(backups.first(), data_modified(), Local::now().naive_local(), interval) {
+        return;
+    }
+    if let Err(e) = create_locked(None) {
+        logging::error("backup", &format!("Scheduled backup failed: {}", e));
+    }
+}
+
+/// Take a backup now if one is due, then check again every minute
+pub fn start_scheduler() {
+    std::thread::spawn(|| loop {
+        run_scheduled();
+        std::thread::sleep(std::time::Duration::from_secs(SCHEDULER_TICK_SECS));
+    });
+}
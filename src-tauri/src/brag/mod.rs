@@ -0,0 +1,245 @@
+use crate::models::{AppData, BragDoc, BragEntry, CycleStart, ReviewCycle, Task};
+use chrono::{Datelike, Local, NaiveDate};
+use serde::Serialize;
+use std::collections::HashSet;
+
+// ============ Draft Entry Suggestions ============
+
+/// A merged PR authored by the user
+#[derive(Debug, Clone)]
+pub struct MergedPr {
+    pub number: u64,
+    pub title: String,
+    pub url: String,
+    pub merged_on: NaiveDate,
+}
+
+fn normalize_title(title: &str) -> String {
+    title.trim().to_lowercase()
+}
+
+fn normalize_url(url: &str) -> String {
+    url.split(['?', '#']).next().unwrap_or(url).trim_end_matches('/').to_lowercase()
+}
+
+/// Propose draft entries for `doc` from merged PRs and tasks completed within
+/// its date range. Drafts whose title or links match an existing entry (or an
+/// earlier draft) are skipped. Drafts are returned oldest first and not saved.
+pub fn draft_entries(doc: &BragDoc, merged_prs: &[MergedPr], tasks: &[Task]) -> Vec<BragEntry> {
+    let in_range = |date: NaiveDate| date >= doc.start_date && date <= doc.end_date;
+
+    let mut seen_titles: HashSet<String> = doc.entries.iter().map(|e| normalize_title(&e.title)).collect();
+    let mut seen_urls: HashSet<String> = doc
+        .entries
+        .iter()
+        .flat_map(|e| e.links.iter().map(|l| normalize_url(l)))
+        .collect();
+
+    let mut drafts: Vec<BragEntry> = Vec::new();
+    let mut push_draft = |entry: BragEntry, drafts: &mut Vec<BragEntry>| {
+        let title = normalize_title(&entry.title);
+        let urls: Vec<String> = entry.links.iter().map(|l| normalize_url(l)).collect();
+        if seen_titles.contains(&title) || urls.iter().any(|u| seen_urls.contains(u)) {
+            return;
+        }
+        seen_titles.insert(title);
+        seen_urls.extend(urls);
+        drafts.push(entry);
+    };
+
+    for pr in merged_prs.iter().filter(|pr| in_range(pr.merged_on)) {
+        let mut entry = BragEntry::new(pr.title.clone(), format!("Merged #{}", pr.number), pr.merged_on);
+        entry.draft = true;
+        entry.links.push(pr.url.clone());
+        push_draft(entry, &mut drafts);
+    }
+
+    for task in tasks.iter().filter(|t| t.completed) {
+        let Some(completed_on) = task.completed_at.map(|c| c.with_timezone(&Local).date_naive()) else {
+            continue;
+        };
+        if !in_range(completed_on) {
+            continue;
+        }
+
+        let mut entry = BragEntry::new(task.title.clone(), String::new(), completed_on);
+        entry.draft = true;
+        entry.tag_ids = task.tag_ids.clone();
+        for url in task.task_url.iter().chain(task.resource_links.iter().map(|l| &l.url)) {
+            if !entry.links.contains(url) {
+                entry.links.push(url.clone());
+            }
+        }
+        push_draft(entry, &mut drafts);
+    }
+
+    drafts.sort_by_key(|e| e.date);
+    drafts
+}
+
+// ============ Category Summary ============
+
+/// Entries in one category; `category` is None for uncategorized entries
+#[derive(Debug, Clone, Serialize)]
+pub struct CategorySummary {
+    pub category: Option<String>,
+    pub count: usize,
+    pub entries: Vec<BragEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BragSummary {
+    pub doc_id: String,
+    pub total: usize,
+    pub categories: Vec<CategorySummary>,
+}
+
+/// Group a doc's entries by category, newest first within each group.
+///
+/// Every configured category is listed (possibly with a zero count) in
+/// configured order, followed by categories that entries still use but are
+/// no longer configured, then uncategorized entries if there are any.
+/// Category names match case-insensitively.
+pub fn summarize(doc: &BragDoc, categories: &[String]) -> BragSummary {
+    let mut groups: Vec<CategorySummary> = categories
+        .iter()
+        .map(|name| CategorySummary {
+            category: Some(name.clone()),
+            count: 0,
+            entries: Vec::new(),
+        })
+        .collect();
+    let mut uncategorized: Vec<BragEntry> = Vec::new();
+
+    let mut entries = doc.entries.clone();
+    entries.sort_by_key(|e| std::cmp::Reverse(e.date));
+
+    for entry in entries {
+        let Some(name) = entry.category.as_deref().map(str::trim).filter(|c| !c.is_empty()) else {
+            uncategorized.push(entry);
+            continue;
+        };
+
+        let existing = groups
+            .iter_mut()
+            .find(|g| g.category.as_deref().is_some_and(|c| c.eq_ignore_ascii_case(name)));
+        match existing {
+            Some(group) => group.entries.push(entry),
+            None => groups.push(CategorySummary {
+                category: Some(name.to_string()),
+                count: 0,
+                entries: vec![entry],
+            }),
+        }
+    }
+
+    if !uncategorized.is_empty() {
+        groups.push(CategorySummary {
+            category: None,
+            count: 0,
+            entries: uncategorized,
+        });
+    }
+
+    for group in &mut groups {
+        group.count = group.entries.len();
+    }
+
+    BragSummary {
+        doc_id: doc.id.clone(),
+        total: doc.entries.len(),
+        categories: groups,
+    }
+}
+
+// ============ Review Cycle Rollover ============
+
+fn cycle_starts(cycle: &ReviewCycle) -> Vec<CycleStart> {
+    let start = |month| CycleStart { month, day: 1 };
+    match cycle {
+        ReviewCycle::Quarterly => vec![start(1), start(4), start(7), start(10)],
+        ReviewCycle::HalfYearly => vec![start(1), start(7)],
+        ReviewCycle::Custom { starts } => starts.clone(),
+    }
+}
+
+/// A cycle start in `year`, with days past the end of the month clamped
+/// (Feb 30 becomes Feb 28/29). Invalid months yield None.
+fn start_in_year(year: i32, start: CycleStart) -> Option<NaiveDate> {
+    (1..=start.day.clamp(1, 31))
+        .rev()
+        .find_map(|day| NaiveDate::from_ymd_opt(year, start.month, day))
+}
+
+/// The review period (start, end inclusive) that contains `date`
+pub fn period_containing(cycle: &ReviewCycle, date: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+    let starts = cycle_starts(cycle);
+    let mut boundaries: Vec<NaiveDate> = (date.year() - 1..=date.year() + 1)
+        .flat_map(|year| starts.iter().filter_map(move |s| start_in_year(year, *s)))
+        .collect();
+    boundaries.sort();
+    boundaries.dedup();
+
+    let i = boundaries.iter().rposition(|b| *b <= date)?;
+    let next = boundaries.get(i + 1)?;
+    Some((boundaries[i], next.pred_opt()?))
+}
+
+fn period_title(cycle: &ReviewCycle, start: NaiveDate, end: NaiveDate) -> String {
+    match cycle {
+        ReviewCycle::Quarterly => format!("Q{} {}", (start.month() - 1) / 3 + 1, start.year()),
+        ReviewCycle::HalfYearly => format!("H{} {}", if start.month() <= 6 { 1 } else { 2 }, start.year()),
+        ReviewCycle::Custom { .. } => format!("{} - {}", start.format("%b %-d, %Y"), end.format("%b %-d, %Y")),
+    }
+}
+
+/// Create the brag doc for the review period containing `today` if no doc
+/// covers today yet. The new doc is trimmed so it doesn't overlap existing
+/// docs, and with `carry_over_drafts` the draft entries of the most recent
+/// earlier doc are moved into it. Returns the new doc's title.
+pub fn rollover(data: &mut AppData, today: NaiveDate) -> Option<String> {
+    let settings = data.settings.review_cycle.clone();
+    let cycle = settings.cycle.as_ref()?;
+
+    if data.brag_docs.iter().any(|d| d.start_date <= today && today <= d.end_date) {
+        return None;
+    }
+
+    let (period_start, period_end) = period_containing(cycle, today)?;
+    let start = data
+        .brag_docs
+        .iter()
+        .map(|d| d.end_date)
+        .filter(|end| *end < today && *end >= period_start)
+        .max()
+        .and_then(|end| end.succ_opt())
+        .unwrap_or(period_start);
+    let end = data
+        .brag_docs
+        .iter()
+        .map(|d| d.start_date)
+        .filter(|start| *start > today && *start <= period_end)
+        .min()
+        .and_then(|start| start.pred_opt())
+        .unwrap_or(period_end);
+
+    let mut doc = BragDoc::new(period_title(cycle, period_start, period_end), start, end);
+
+    if settings.carry_over_drafts {
+        let previous = data
+            .brag_docs
+            .iter_mut()
+            .filter(|d| d.end_date < today)
+            .max_by_key(|d| d.end_date);
+        if let Some(previous) = previous {
+            let (drafts, kept): (Vec<BragEntry>, Vec<BragEntry>) =
+                std::mem::take(&mut previous.entries).into_iter().partition(|e| e.draft);
+            previous.entries = kept;
+            doc.entries = drafts;
+        }
+    }
+
+    let title = doc.title.clone();
+    data.brag_docs.push(doc);
+    Some(title)
+}
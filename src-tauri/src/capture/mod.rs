@@ -0,0 +1,256 @@
+use crate::dates::{self, WeekStart};
+use crate::error::AppError;
+use crate::models::{AppData, LinkType, Note, PrApproval, ResourceLink, Tag, Task, TaskType, TAG_COLORS};
+use chrono::NaiveDate;
+use serde::Serialize;
+
+// ============ Quick Capture Parsing ============
+//
+// Quick capture input is free text with a few inline markers:
+//   #tag          attach a tag (created if it doesn't exist yet)
+//   @today        schedule for today (the default for tasks)
+//   @tomorrow     schedule using any expression `dates` understands,
+//   @next-tue     with dashes or underscores in place of spaces
+//   @2025-01-31
+//   @backlog      leave the task unscheduled
+//   !pr <url>     create a PR review task for the given GitHub or Graphite PR URL
+//   !note         create a note instead of a task
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureKind {
+    Task,
+    Note,
+}
+
+#[derive(Debug, Clone)]
+pub struct QuickCapture {
+    pub kind: CaptureKind,
+    pub text: String,
+    pub tag_names: Vec<String>,
+    pub scheduled_date: Option<NaiveDate>,
+    pub pr_url: Option<String>,
+}
+
+/// Result of a quick capture, returned to the capture window
+#[derive(Debug, Clone, Serialize)]
+pub struct CaptureResult {
+    pub kind: CaptureKind,
+    pub id: String,
+    pub title: String,
+}
+
+fn parse_schedule_marker(marker: &str, today: NaiveDate, week_start: WeekStart) -> Result<Option<NaiveDate>, AppError> {
+    if marker.eq_ignore_ascii_case("backlog") {
+        return Ok(None);
+    }
+
+    // Markers can't contain spaces, so "@next-tue" and "@in_3_days" stand in for them
+    dates::parse_date_expression(marker, today, week_start)
+        .or_else(|| dates::parse_date_expression(&marker.replace(['-', '_'], " "), today, week_start))
+        .map(Some)
+        .ok_or_else(|| AppError::InvalidInput(format!("Unrecognized date: @{}", marker)))
+}
+
+/// Parse quick capture input relative to `today`
+pub fn parse(input: &str, today: NaiveDate, week_start: WeekStart) -> Result<QuickCapture, AppError> {
+    let mut kind = CaptureKind::Task;
+    let mut words: Vec<&str> = Vec::new();
+    let mut tag_names: Vec<String> = Vec::new();
+    let mut scheduled_date = Some(today);
+    let mut pr_url: Option<String> = None;
+
+    let mut tokens = input.split_whitespace();
+    while let Some(token) = tokens.next() {
+        if let Some(tag) = token.strip_prefix('#').filter(|t| !t.is_empty()) {
+            if !tag_names.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                tag_names.push(tag.to_string());
+            }
+        } else if let Some(marker) = token.strip_prefix('@').filter(|m| !m.is_empty()) {
+            scheduled_date = parse_schedule_marker(marker, today, week_start)?;
+        } else if let Some(action) = token.strip_prefix('!').filter(|a| !a.is_empty()) {
+            match action.to_lowercase().as_str() {
+                "pr" => {
+                    let url = tokens
+                        .next()
+                        .ok_or_else(|| AppError::InvalidInput("!pr requires a PR URL".to_string()))?;
+                    match classify_url(url) {
+                        Some((UrlKind::PullRequest, url)) => pr_url = Some(url),
+                        _ => return Err(AppError::InvalidInput(format!("!pr requires a PR URL, not \"{}\"", url))),
+                    }
+                }
+                "note" => kind = CaptureKind::Note,
+                _ => return Err(AppError::InvalidInput(format!("Unknown quick capture action: !{}", action))),
+            }
+        } else {
+            words.push(token);
+        }
+    }
+
+    if kind == CaptureKind::Note && pr_url.is_some() {
+        return Err(AppError::InvalidInput("!pr and !note can't be combined".to_string()));
+    }
+
+    let text = words.join(" ");
+    if text.is_empty() && pr_url.is_none() {
+        return Err(AppError::InvalidInput("Nothing to capture".to_string()));
+    }
+
+    Ok(QuickCapture {
+        kind,
+        text,
+        tag_names,
+        scheduled_date,
+        pr_url,
+    })
+}
+
+/// Look up tags by name (case-insensitive), creating any that don't exist yet
+fn resolve_tag_ids(data: &mut AppData, tag_names: &[String]) -> Vec<String> {
+    tag_names
+        .iter()
+        .map(|name| {
+            if let Some(tag) = data.tags.iter().find(|t| t.name.eq_ignore_ascii_case(name)) {
+                return tag.id.clone();
+            }
+            let color = TAG_COLORS[data.tags.len() % TAG_COLORS.len()];
+            let tag = Tag::new(name.clone(), color.to_string());
+            let id = tag.id.clone();
+            data.tags.push(tag);
+            id
+        })
+        .collect()
+}
+
+/// Apply a parsed capture to `data`, creating the task or note.
+///
+/// `pr_details` carries the fetched title and approvals for `!pr` captures;
+/// when absent the PR URL is used as the title.
+pub fn apply(
+    data: &mut AppData,
+    capture: QuickCapture,
+    pr_details: Option<(String, Vec<PrApproval>)>,
+) -> CaptureResult {
+    let tag_ids = resolve_tag_ids(data, &capture.tag_names);
+
+    match capture.kind {
+        CaptureKind::Note => {
+            let mut note = Note::new(capture.text);
+            note.tag_ids = tag_ids;
+            let result = CaptureResult {
+                kind: CaptureKind::Note,
+                id: note.id.clone(),
+                title: note.content.lines().next().unwrap_or_default().to_string(),
+            };
+            data.notes.push(note);
+            result
+        }
+        CaptureKind::Task => {
+            let mut task = Task::new(capture.text);
+            task.scheduled_date = capture.scheduled_date;
+            task.tag_ids = tag_ids;
+
+            if let Some(url) = capture.pr_url {
+                let (pr_title, approvals) = pr_details.unwrap_or_default();
+                if task.title.is_empty() {
+                    task.title = if pr_title.is_empty() { url.clone() } else { pr_title.clone() };
+                }
+                task.task_type = TaskType::PrReview;
+                task.pr_approvals = Some(approvals);
+                task.resource_links.push(ResourceLink::new(
+                    url.clone(),
+                    if pr_title.is_empty() { url.clone() } else { pr_title },
+                    LinkType::GithubPr,
+                ));
+                task.task_url = Some(url);
+            }
+
+            let result = CaptureResult {
+                kind: CaptureKind::Task,
+                id: task.id.clone(),
+                title: task.title.clone(),
+            };
+            data.tasks.push(task);
+            result
+        }
+    }
+}
+
+// ============ Tasks From URLs ============
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UrlKind {
+    PullRequest,
+    Issue,
+    Link,
+}
+
+/// Result of creating a task from a URL; `created` is false when an existing
+/// task already tracks the same URL
+#[derive(Debug, Clone, Serialize)]
+pub struct UrlTaskResult {
+    pub kind: UrlKind,
+    pub task_id: String,
+    pub title: String,
+    pub created: bool,
+}
+
+/// Classify a URL and return its canonical form. Graphite PR links are
+/// rewritten to their GitHub equivalent so both dedupe against each other.
+pub fn classify_url(url: &str) -> Option<(UrlKind, String)> {
+    let url = url.trim();
+    if !(url.starts_with("https://") || url.starts_with("http://")) || url.contains(char::is_whitespace) {
+        return None;
+    }
+
+    if let Some((org, repo, number)) = crate::commands::parse_pr_url(url) {
+        return Some((
+            UrlKind::PullRequest,
+            format!("https://github.com/{}/{}/pull/{}", org, repo, number),
+        ));
+    }
+
+    if let Some((org, repo, number)) = crate::commands::parse_issue_url(url) {
+        return Some((
+            UrlKind::Issue,
+            format!("https://github.com/{}/{}/issues/{}", org, repo, number),
+        ));
+    }
+
+    Some((UrlKind::Link, url.trim_end_matches('/').to_string()))
+}
+
+/// Find a task outside the trash whose URL or resource links point at `canonical_url`
+pub fn find_task_by_url<'a>(data: &'a AppData, canonical_url: &str) -> Option<&'a Task> {
+    let matches = |url: &str| classify_url(url).is_some_and(|(_, canonical)| canonical == canonical_url);
+
+    data.tasks.iter().filter(|task| task.deleted_at.is_none()).find(|task| {
+        task.task_url.as_deref().is_some_and(matches) || task.resource_links.iter().any(|link| matches(&link.url))
+    })
+}
+
+/// Build a task for a classified URL, scheduled for `today`
+pub fn task_from_url(
+    kind: UrlKind,
+    url: String,
+    title: String,
+    approvals: Option<Vec<PrApproval>>,
+    today: NaiveDate,
+) -> Task {
+    let (task_type, link_type) = match kind {
+        UrlKind::PullRequest => (TaskType::PrReview, LinkType::GithubPr),
+        UrlKind::Issue => (TaskType::GithubIssue, LinkType::GithubIssue),
+        UrlKind::Link => (TaskType::Regular, LinkType::Url),
+    };
+
+    let mut task = Task::new(title.clone());
+    task.scheduled_date = Some(today);
+    task.task_type = task_type;
+    task.pr_approvals = approvals;
+    task.resource_links.push(ResourceLink::new(url.clone(), title, link_type));
+    if kind != UrlKind::Link {
+        task.task_url = Some(url);
+    }
+    task
+}
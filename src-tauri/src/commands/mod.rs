@@ -1,5 +1,25 @@
-use crate::models::{AppData, GitHubPr, PrApproval};
+use crate::backup::{self, BackupItem, BackupPreview, ConflictStrategy, ItemKind, ItemRef, RestoredItem};
+use crate::brag;
+use crate::capture::{self, CaptureResult, UrlKind, UrlTaskResult};
+use crate::dates;
+use crate::diagnostics::{self, CheckStatus, DiagnosticCheck, DiagnosticsReport};
+use crate::error::AppError;
+use crate::export::{self, ExportFormat, ExportGrouping, ExportedFile};
+use crate::focus::{self, FocusStatus};
+use crate::github_stats;
+use crate::history::{self, HistoryStatus, StepResult};
+use crate::images::{self, CleanupResult, ImageScan, SavedImage};
+use crate::logging::{self, LogEntry, LogLevel};
+use crate::models::{
+    AppData, BragEntry, FocusSession, GitHubPr, Note, Notification, NotificationAction, PrApproval,
+    ScheduleType, Settings,
+};
+use crate::review;
+use crate::shortcuts::{self, ShortcutStatus};
+use crate::stats::{self, ProductivityStats};
 use crate::storage;
+use crate::timer::{self, RunningTimer, TimeTotals};
+use crate::trash::{self, TrashItem, TrashKind};
 use once_cell::sync::Lazy;
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
@@ -7,6 +27,8 @@ use std::collections::{HashMap, HashSet};
 use std::process::{Command, Stdio};
 use std::time::{Duration, Instant};
 use tauri::Emitter;
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri_plugin_dialog::DialogExt;
 
 // ============ Code Review Event Payloads ============
 
@@ -60,10 +82,10 @@ fn find_gh_path() -> Option<String> {
 /// Cached gh path - computed once on first use
 static GH_PATH: Lazy<Option<String>> = Lazy::new(find_gh_path);
 
-fn get_gh_path() -> Result<&'static str, String> {
+fn get_gh_path() -> Result<&'static str, AppError> {
     GH_PATH.as_ref()
         .map(|s| s.as_str())
-        .ok_or_else(|| "GitHub CLI (gh) not found. Please install it: https://cli.github.com/".to_string())
+        .ok_or(AppError::GhNotInstalled)
 }
 
 // ============ PR Cache ============
@@ -121,7 +143,7 @@ struct GhReviewResponse {
 }
 
 /// Parse a PR URL (GitHub or Graphite) and return (org, repo, pr_number)
-fn parse_pr_url(url: &str) -> Option<(String, String, String)> {
+pub fn parse_pr_url(url: &str) -> Option<(String, String, String)> {
     let clean_url = url.split('?').next().unwrap_or(url).trim_end_matches('/');
 
     // GitHub: https://github.com/org/repo/pull/123
@@ -147,20 +169,20 @@ fn parse_pr_url(url: &str) -> Option<(String, String, String)> {
 }
 
 #[tauri::command]
-pub fn fetch_pr_info(url: String) -> Result<(String, Vec<PrApproval>), String> {
+pub fn fetch_pr_info(url: String) -> Result<(String, Vec<PrApproval>), AppError> {
     let (org, repo, pr_num) = parse_pr_url(&url)
-        .ok_or_else(|| "Invalid PR URL format".to_string())?;
+        .ok_or_else(|| AppError::InvalidInput("Invalid PR URL format".to_string()))?;
 
     let gh_path = get_gh_path()?;
 
     let title_output = Command::new(gh_path)
         .args(["api", &format!("repos/{}/{}/pulls/{}", org, repo, pr_num), "--jq", ".title"])
         .output()
-        .map_err(|e| format!("Failed to run gh command: {}", e))?;
+        .map_err(AppError::from_gh_spawn)?;
 
     if !title_output.status.success() {
         let stderr = String::from_utf8_lossy(&title_output.stderr);
-        return Err(format!("Failed to fetch PR title: {}", stderr));
+        return Err(AppError::from_gh_stderr("Failed to fetch PR title", &stderr));
     }
 
     let title = String::from_utf8_lossy(&title_output.stdout).trim().to_string();
@@ -168,7 +190,7 @@ pub fn fetch_pr_info(url: String) -> Result<(String, Vec<PrApproval>), String> {
     let reviews_output = Command::new(gh_path)
         .args(["api", &format!("repos/{}/{}/pulls/{}/reviews", org, repo, pr_num)])
         .output()
-        .map_err(|e| format!("Failed to run gh command: {}", e))?;
+        .map_err(AppError::from_gh_spawn)?;
 
     let mut approvals: Vec<PrApproval> = Vec::new();
 
@@ -194,7 +216,7 @@ pub fn fetch_pr_info(url: String) -> Result<(String, Vec<PrApproval>), String> {
 }
 
 /// Parse a GitHub issue URL and return (org, repo, issue_number)
-fn parse_issue_url(url: &str) -> Option<(String, String, String)> {
+pub fn parse_issue_url(url: &str) -> Option<(String, String, String)> {
     let clean_url = url.split('?').next().unwrap_or(url).trim_end_matches('/');
 
     if let Some(caps) = clean_url.strip_prefix("https://github.com/") {
@@ -208,19 +230,19 @@ fn parse_issue_url(url: &str) -> Option<(String, String, String)> {
 }
 
 #[tauri::command]
-pub fn fetch_issue_info(url: String) -> Result<String, String> {
+pub fn fetch_issue_info(url: String) -> Result<String, AppError> {
     let (org, repo, issue_num) = parse_issue_url(&url)
-        .ok_or_else(|| "Invalid GitHub issue URL format".to_string())?;
+        .ok_or_else(|| AppError::InvalidInput("Invalid GitHub issue URL format".to_string()))?;
 
     let gh_path = get_gh_path()?;
     let title_output = Command::new(gh_path)
         .args(["api", &format!("repos/{}/{}/issues/{}", org, repo, issue_num), "--jq", ".title"])
         .output()
-        .map_err(|e| format!("Failed to run gh command: {}", e))?;
+        .map_err(AppError::from_gh_spawn)?;
 
     if !title_output.status.success() {
         let stderr = String::from_utf8_lossy(&title_output.stderr);
-        return Err(format!("Failed to fetch issue title: {}", stderr));
+        return Err(AppError::from_gh_stderr("Failed to fetch issue title", &stderr));
     }
 
     let title = String::from_utf8_lossy(&title_output.stdout).trim().to_string();
@@ -228,63 +250,595 @@ pub fn fetch_issue_info(url: String) -> Result<String, String> {
 }
 
 #[tauri::command]
-pub fn get_all_data() -> Result<AppData, String> {
+pub fn get_all_data() -> Result<AppData, AppError> {
     storage::load_data()
 }
 
+/// Pick up shortcut, log level, backup, trash and image cleanup changes without a restart
+fn apply_settings(app_handle: &tauri::AppHandle, settings: &Settings) {
+    shortcuts::sync(app_handle, &settings.shortcuts);
+    logging::configure(&settings.logging);
+    backup::configure(&settings.backups);
+    trash::configure(&settings.trash);
+    images::configure(&settings.image_cleanup);
+}
+
+#[tauri::command]
+pub fn save_all_data(app_handle: tauri::AppHandle, mut data: AppData) -> Result<(), AppError> {
+    // Completing or archiving a task in the UI ends its timer
+    timer::stop_finished(&mut data, chrono::Utc::now());
+    let before = storage::load_data().ok();
+    storage::save_data(&data)?;
+
+    if let Some(before) = before {
+        history::record(&app_handle, None, &before, &data);
+    }
+    apply_settings(&app_handle, &data.settings);
+    Ok(())
+}
+
+// ============ Undo History ============
+
+/// Revert the most recent change to the data, leaving alone anything
+/// edited again since
+#[tauri::command]
+pub fn undo(app_handle: tauri::AppHandle) -> Result<StepResult, AppError> {
+    let (data, result) = history::undo(&app_handle)?;
+    apply_settings(&app_handle, &data.settings);
+    Ok(result)
+}
+
+/// Reapply the most recently undone change
 #[tauri::command]
-pub fn save_all_data(data: AppData) -> Result<(), String> {
-    storage::save_data(&data)
+pub fn redo(app_handle: tauri::AppHandle) -> Result<StepResult, AppError> {
+    let (data, result) = history::redo(&app_handle)?;
+    apply_settings(&app_handle, &data.settings);
+    Ok(result)
 }
 
 #[tauri::command]
-pub fn create_backup() -> Result<String, String> {
-    storage::create_backup()
+pub fn get_history_status() -> Result<HistoryStatus, AppError> {
+    Ok(history::status())
 }
 
 #[tauri::command]
-pub fn get_backups() -> Result<Vec<String>, String> {
-    storage::get_backups()
+pub fn create_backup() -> Result<String, AppError> {
+    backup::create(Some(backup::REASON_MANUAL))
 }
 
 #[tauri::command]
-pub fn restore_backup(backup_name: String) -> Result<AppData, String> {
-    storage::restore_backup(&backup_name)
+pub fn get_backups() -> Result<Vec<String>, AppError> {
+    Ok(backup::list().into_iter().map(|b| b.name).collect())
 }
 
+/// Verify a backup and diff it against the current data without restoring
 #[tauri::command]
-pub fn save_image(filename: String, data: Vec<u8>) -> Result<String, String> {
-    storage::save_image(&filename, &data)
+pub async fn preview_backup(backup_name: String) -> Result<BackupPreview, AppError> {
+    tauri::async_runtime::spawn_blocking(move || backup::preview(&backup_name))
+        .await
+        .map_err(|e| format!("Task failed: {}", e))?
+}
+
+/// Restore a verified backup, snapshotting the current data first, and
+/// tell open windows to reload
+#[tauri::command]
+pub async fn restore_backup(app_handle: tauri::AppHandle, backup_name: String) -> Result<AppData, AppError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let before = storage::load_data().ok();
+        let data = backup::restore(&backup_name)?;
+        if let Some(before) = before {
+            history::record(&app_handle, Some("Restore backup"), &before, &data);
+        }
+        let _ = app_handle.emit("data::changed", ());
+        Ok(data)
+    })
+    .await
+    .map_err(|e| format!("Task failed: {}", e))?
+}
+
+/// Tasks, notes, brag docs or brag entries in a backup, with how each
+/// compares to the current data
+#[tauri::command]
+pub async fn get_backup_items(backup_name: String, kind: ItemKind) -> Result<Vec<BackupItem>, AppError> {
+    tauri::async_runtime::spawn_blocking(move || backup::browse(&backup_name, kind))
+        .await
+        .map_err(|e| format!("Task failed: {}", e))?
+}
+
+/// Copy selected items out of a backup into the current data. Conflicting
+/// ids keep both versions unless `on_conflict` is "replace".
+#[tauri::command]
+pub async fn restore_backup_items(
+    app_handle: tauri::AppHandle,
+    backup_name: String,
+    items: Vec<ItemRef>,
+    on_conflict: Option<ConflictStrategy>,
+) -> Result<Vec<RestoredItem>, AppError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let before = storage::load_data()?;
+        let restored = backup::restore_selected(&backup_name, &items, on_conflict.unwrap_or_default())?;
+        history::record(&app_handle, Some("Restore from backup"), &before, &storage::load_data()?);
+        let _ = app_handle.emit("data::changed", ());
+        Ok(restored)
+    })
+    .await
+    .map_err(|e| format!("Task failed: {}", e))?
+}
+
+// ============ Trash ============
+
+/// Move a task, note, brag entry or reminder to the trash
+#[tauri::command]
+pub fn trash_item(app_handle: tauri::AppHandle, kind: TrashKind, id: String) -> Result<(), AppError> {
+    let mut data = storage::load_data()?;
+    let before = data.clone();
+    trash::trash(&mut data, kind, &id, chrono::Utc::now())?;
+    storage::save_data(&data)?;
+    history::record(&app_handle, None, &before, &data);
+
+    let _ = app_handle.emit("data::changed", ());
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_trash() -> Result<Vec<TrashItem>, AppError> {
+    Ok(trash::list(&storage::load_data()?, trash::policy().retention_days))
+}
+
+#[tauri::command]
+pub fn restore_trash_item(app_handle: tauri::AppHandle, kind: TrashKind, id: String) -> Result<(), AppError> {
+    let mut data = storage::load_data()?;
+    let before = data.clone();
+    trash::restore(&mut data, kind, &id)?;
+    storage::save_data(&data)?;
+    history::record(&app_handle, None, &before, &data);
+
+    let _ = app_handle.emit("data::changed", ());
+    Ok(())
 }
 
+/// Delete a trashed item for good, along with images nothing else uses
 #[tauri::command]
-pub fn delete_image(filename: String) -> Result<(), String> {
+pub fn purge_trash_item(app_handle: tauri::AppHandle, kind: TrashKind, id: String) -> Result<(), AppError> {
+    let mut data = storage::load_data()?;
+    let before = data.clone();
+    let images = trash::purge_item(&mut data, kind, &id)?;
+    storage::save_data(&data)?;
+    history::record(&app_handle, None, &before, &data);
+    trash::delete_images(&images);
+
+    let _ = app_handle.emit("data::changed", ());
+    Ok(())
+}
+
+/// Delete everything in the trash, returning how many items were removed
+#[tauri::command]
+pub fn empty_trash(app_handle: tauri::AppHandle) -> Result<usize, AppError> {
+    let mut data = storage::load_data()?;
+    let before = data.clone();
+    let (removed, images) = trash::empty(&mut data);
+    if removed > 0 {
+        storage::save_data(&data)?;
+        history::record(&app_handle, Some("Empty trash"), &before, &data);
+        trash::delete_images(&images);
+        let _ = app_handle.emit("data::changed", ());
+    }
+    Ok(removed)
+}
+
+// ============ Unused Images ============
+
+/// Images nothing refers to, checking current data, backups and undo history
+#[tauri::command]
+pub async fn scan_images() -> Result<ImageScan, AppError> {
+    tauri::async_runtime::spawn_blocking(images::scan)
+        .await
+        .map_err(|e| format!("Task failed: {}", e))?
+}
+
+/// Delete images confirmed from a scan; any that have since been put to use are kept
+#[tauri::command]
+pub async fn delete_unused_images(names: Vec<String>) -> Result<CleanupResult, AppError> {
+    tauri::async_runtime::spawn_blocking(move || images::delete_unused(&names))
+        .await
+        .map_err(|e| format!("Task failed: {}", e))?
+}
+
+/// Store an uploaded image under its content hash; identical images share a file
+#[tauri::command]
+pub fn save_image(filename: String, data: Vec<u8>) -> Result<SavedImage, AppError> {
+    images::save(&filename, &data)
+}
+
+#[tauri::command]
+pub fn delete_image(filename: String) -> Result<(), AppError> {
     storage::delete_image(&filename)
 }
 
 #[tauri::command]
-pub fn get_app_data_path() -> Result<String, String> {
+pub fn get_app_data_path() -> Result<String, AppError> {
     Ok(storage::get_app_dir().to_string_lossy().to_string())
 }
 
-// Simple file logger for debugging production builds
-fn log_debug(msg: &str) {
-    use std::io::Write;
-    let log_path = "/tmp/atulify-debug.log";
-    if let Ok(mut file) = std::fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(log_path)
-    {
-        let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-        let _ = writeln!(file, "[{}] {}", timestamp, msg);
+// ============ Quick Capture ============
+
+/// Parse quick capture input and store the resulting task or note directly,
+/// then notify open windows so they reload instead of saving stale data
+#[tauri::command]
+pub async fn quick_capture(app_handle: tauri::AppHandle, input: String) -> Result<CaptureResult, AppError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let today = chrono::Local::now().date_naive();
+        let week_start = storage::load_data()?.settings.resolved_week_start();
+        let parsed = capture::parse(&input, today, week_start)?;
+
+        // Fetch PR details up front; on failure the URL is used as the title
+        let pr_details = parsed
+            .pr_url
+            .as_ref()
+            .and_then(|url| fetch_pr_info(url.clone()).ok());
+
+        // Reload after the gh call so we don't overwrite changes made meanwhile
+        let mut data = storage::load_data()?;
+        let before = data.clone();
+        let result = capture::apply(&mut data, parsed, pr_details);
+        storage::save_data(&data)?;
+        history::record(&app_handle, None, &before, &data);
+
+        let _ = app_handle.emit("data::changed", ());
+        Ok(result)
+    })
+    .await
+    .map_err(|e| format!("Task failed: {}", e))?
+}
+
+// ============ Global Shortcuts ============
+
+/// Registration status of each configured shortcut, including conflicts
+#[tauri::command]
+pub fn get_shortcut_status() -> Result<Vec<ShortcutStatus>, AppError> {
+    Ok(shortcuts::statuses())
+}
+
+// ============ Tasks From URLs ============
+
+fn create_task_from_url_blocking(app_handle: &tauri::AppHandle, url: &str) -> Result<UrlTaskResult, AppError> {
+    let (kind, url) = capture::classify_url(url).ok_or_else(|| AppError::InvalidInput(format!("Not a URL: {}", url.trim())))?;
+
+    if let Some(existing) = capture::find_task_by_url(&storage::load_data()?, &url) {
+        return Ok(UrlTaskResult {
+            kind,
+            task_id: existing.id.clone(),
+            title: existing.title.clone(),
+            created: false,
+        });
+    }
+
+    // A failed lookup still creates the task, titled with the URL
+    let (title, approvals) = match kind {
+        UrlKind::PullRequest => fetch_pr_info(url.clone())
+            .map(|(title, approvals)| (title, Some(approvals)))
+            .unwrap_or_else(|_| (url.clone(), Some(Vec::new()))),
+        UrlKind::Issue => (fetch_issue_info(url.clone()).unwrap_or_else(|_| url.clone()), None),
+        UrlKind::Link => (url.clone(), None),
+    };
+
+    // Reload after the gh calls so we don't overwrite changes made meanwhile
+    let mut data = storage::load_data()?;
+    if let Some(existing) = capture::find_task_by_url(&data, &url) {
+        return Ok(UrlTaskResult {
+            kind,
+            task_id: existing.id.clone(),
+            title: existing.title.clone(),
+            created: false,
+        });
+    }
+
+    let today = chrono::Local::now().date_naive();
+    let task = capture::task_from_url(kind, url, title, approvals, today);
+    let result = UrlTaskResult {
+        kind,
+        task_id: task.id.clone(),
+        title: task.title.clone(),
+        created: true,
+    };
+    let before = data.clone();
+    data.tasks.push(task);
+    storage::save_data(&data)?;
+    history::record(app_handle, None, &before, &data);
+
+    let _ = app_handle.emit("data::changed", ());
+    Ok(result)
+}
+
+/// Create a task for a GitHub PR, GitHub issue, or plain URL
+#[tauri::command]
+pub async fn create_task_from_url(app_handle: tauri::AppHandle, url: String) -> Result<UrlTaskResult, AppError> {
+    tauri::async_runtime::spawn_blocking(move || create_task_from_url_blocking(&app_handle, &url))
+        .await
+        .map_err(|e| format!("Task failed: {}", e))?
+}
+
+/// Create a task for the URL currently on the clipboard
+#[tauri::command]
+pub async fn create_task_from_clipboard(app_handle: tauri::AppHandle) -> Result<UrlTaskResult, AppError> {
+    let url = app_handle
+        .clipboard()
+        .read_text()
+        .map_err(|e| format!("Failed to read clipboard: {}", e))?;
+
+    create_task_from_url(app_handle, url).await
+}
+
+// ============ Brag Doc Export ============
+
+/// Export a brag doc to a file chosen in a save dialog.
+/// Returns the written path and anything left out, or None if the dialog
+/// was cancelled.
+#[tauri::command]
+pub async fn export_brag_doc(
+    app_handle: tauri::AppHandle,
+    doc_id: String,
+    format: ExportFormat,
+    grouping: Option<ExportGrouping>,
+) -> Result<Option<ExportedFile>, AppError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let data = trash::visible(&storage::load_data()?);
+        let doc = data
+            .brag_docs
+            .iter()
+            .find(|d| d.id == doc_id)
+            .ok_or_else(|| AppError::NotFound(format!("Brag doc '{}' not found", doc_id)))?;
+
+        let Some(path) = app_handle
+            .dialog()
+            .file()
+            .set_title("Export Brag Doc")
+            .set_file_name(export::default_file_name(doc, format))
+            .add_filter(format.filter_name(), &[format.extension()])
+            .blocking_save_file()
+        else {
+            return Ok(None);
+        };
+        let path = path.into_path().map_err(|e| format!("Invalid export path: {}", e))?;
+
+        let rendered = export::export(
+            doc,
+            &data.tags,
+            format,
+            grouping.unwrap_or_default(),
+            &storage::get_images_dir(),
+        )?;
+        std::fs::write(&path, rendered.contents).map_err(|e| format!("Failed to write export: {}", e))?;
+        for warning in &rendered.warnings {
+            logging::warn("export", warning);
+        }
+
+        Ok(Some(ExportedFile {
+            path: path.to_string_lossy().to_string(),
+            warnings: rendered.warnings,
+        }))
+    })
+    .await
+    .map_err(|e| format!("Task failed: {}", e))?
+}
+
+// ============ Brag Doc Summary ============
+
+/// Entry counts and entries grouped by category for a brag doc
+#[tauri::command]
+pub fn get_brag_summary(doc_id: String) -> Result<brag::BragSummary, AppError> {
+    let data = trash::visible(&storage::load_data()?);
+    let doc = data
+        .brag_docs
+        .iter()
+        .find(|d| d.id == doc_id)
+        .ok_or_else(|| AppError::NotFound(format!("Brag doc '{}' not found", doc_id)))?;
+
+    Ok(brag::summarize(doc, &data.settings.brag_categories))
+}
+
+// ============ Time Tracking ============
+
+#[tauri::command]
+pub fn start_timer(app_handle: tauri::AppHandle, task_id: String) -> Result<RunningTimer, AppError> {
+    let mut data = storage::load_data()?;
+    let before = data.clone();
+    let timer = timer::start(&mut data, &task_id, chrono::Utc::now())?;
+    storage::save_data(&data)?;
+    history::record(&app_handle, Some(&format!("Start timer on \"{}\"", timer.title)), &before, &data);
+
+    let _ = app_handle.emit("data::changed", ());
+    Ok(timer)
+}
+
+/// Stop a task's timer, returning the seconds added
+#[tauri::command]
+pub fn stop_timer(app_handle: tauri::AppHandle, task_id: String) -> Result<i64, AppError> {
+    let mut data = storage::load_data()?;
+    let before = data.clone();
+    let seconds = timer::stop(&mut data, &task_id, chrono::Utc::now())?;
+    storage::save_data(&data)?;
+    let title = data.tasks.iter().find(|t| t.id == task_id).map(|t| t.title.as_str()).unwrap_or_default();
+    history::record(&app_handle, Some(&format!("Stop timer on \"{}\"", title)), &before, &data);
+
+    let _ = app_handle.emit("data::changed", ());
+    Ok(seconds)
+}
+
+#[tauri::command]
+pub fn get_running_timer() -> Result<Option<RunningTimer>, AppError> {
+    Ok(timer::running(&storage::load_data()?))
+}
+
+/// Tracked time per tag and per day between two dates (inclusive)
+#[tauri::command]
+pub fn get_time_totals(start: chrono::NaiveDate, end: chrono::NaiveDate) -> Result<TimeTotals, AppError> {
+    if end < start {
+        return Err(AppError::InvalidInput("End date is before start date".to_string()));
+    }
+    Ok(timer::totals(&trash::visible(&storage::load_data()?), start, end, chrono::Utc::now()))
+}
+
+// ============ Focus Sessions ============
+
+/// Start a pomodoro on a task, replacing any session already running
+#[tauri::command]
+pub fn start_focus(app_handle: tauri::AppHandle, task_id: String) -> Result<FocusStatus, AppError> {
+    focus::start(&app_handle, &task_id)
+}
+
+/// Stop the running session; returns the logged partial work phase, if any
+#[tauri::command]
+pub fn stop_focus(app_handle: tauri::AppHandle) -> Result<Option<FocusSession>, AppError> {
+    Ok(focus::stop(&app_handle))
+}
+
+#[tauri::command]
+pub fn get_focus_status() -> Result<Option<FocusStatus>, AppError> {
+    Ok(focus::status())
+}
+
+// ============ Productivity Stats ============
+
+/// Local stats for a date range, defaulting to the last 12 weeks
+#[tauri::command]
+pub fn get_productivity_stats(
+    start: Option<chrono::NaiveDate>,
+    end: Option<chrono::NaiveDate>,
+) -> Result<ProductivityStats, AppError> {
+    let data = trash::visible(&storage::load_data()?);
+    let today = chrono::Local::now().date_naive();
+    let end = end.unwrap_or(today);
+    let start = start.unwrap_or(end - chrono::Duration::weeks(12) + chrono::Duration::days(1));
+    if end < start {
+        return Err(AppError::InvalidInput("End date is before start date".to_string()));
+    }
+    Ok(stats::compute(&data, start, end, today, data.settings.resolved_week_start()))
+}
+
+// ============ Logs ============
+
+const DEFAULT_LOG_LIMIT: usize = 200;
+
+/// Recent log entries at `level` or more severe (default: warnings and
+/// errors), newest first
+#[tauri::command]
+pub async fn get_recent_logs(limit: Option<usize>, level: Option<LogLevel>) -> Result<Vec<LogEntry>, AppError> {
+    let limit = limit.unwrap_or(DEFAULT_LOG_LIMIT);
+    let level = level.unwrap_or(LogLevel::Warn);
+    tauri::async_runtime::spawn_blocking(move || logging::recent(limit, level))
+        .await
+        .map_err(|e| AppError::Internal(format!("Task failed: {}", e)))
+}
+
+// ============ Diagnostics ============
+
+/// gh install, auth and rate limit checks; later ones are skipped when gh
+/// is missing or logged out
+fn gh_checks() -> Vec<DiagnosticCheck> {
+    let mut checks = Vec::new();
+
+    let gh_path = match get_gh_path() {
+        Ok(path) => {
+            checks.push(DiagnosticCheck::new("gh_path", "GitHub CLI", CheckStatus::Ok, path));
+            path
+        }
+        Err(e) => {
+            checks.push(DiagnosticCheck::new("gh_path", "GitHub CLI", CheckStatus::Error, e.to_string()));
+            return checks;
+        }
+    };
+
+    // gh prints its auth status to stderr on older versions and stdout on newer
+    let auth = match Command::new(gh_path).args(["auth", "status", "--hostname", "github.com"]).output() {
+        Ok(output) => {
+            let text = format!(
+                "{}{}",
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr)
+            );
+            if output.status.success() {
+                let account = text
+                    .lines()
+                    .map(str::trim)
+                    .find(|l| l.contains("Logged in"))
+                    .map(|l| l.trim_start_matches(|c: char| !c.is_alphanumeric()).to_string())
+                    .unwrap_or_else(|| "Logged in".to_string());
+                DiagnosticCheck::new("gh_auth", "GitHub Login", CheckStatus::Ok, account)
+            } else {
+                let detail = AppError::from_gh_stderr("gh auth status failed", &text).to_string();
+                DiagnosticCheck::new("gh_auth", "GitHub Login", CheckStatus::Error, detail)
+            }
+        }
+        Err(e) => DiagnosticCheck::new(
+            "gh_auth",
+            "GitHub Login",
+            CheckStatus::Error,
+            AppError::from_gh_spawn(e).to_string(),
+        ),
+    };
+    let authenticated = auth.status == CheckStatus::Ok;
+    checks.push(auth);
+    if !authenticated {
+        return checks;
+    }
+
+    match Command::new(gh_path).args(["api", "rate_limit"]).output() {
+        Ok(output) if output.status.success() => {
+            checks.extend(diagnostics::rate_limit_checks(&String::from_utf8_lossy(&output.stdout)));
+        }
+        Ok(output) => {
+            let error = AppError::from_gh_stderr("Failed to fetch rate limits", &String::from_utf8_lossy(&output.stderr));
+            let status = match error {
+                AppError::NetworkUnavailable | AppError::RateLimited => CheckStatus::Error,
+                _ => CheckStatus::Warning,
+            };
+            checks.push(DiagnosticCheck::new("rate_limit", "GitHub Rate Limits", status, error.to_string()));
+        }
+        Err(e) => checks.push(DiagnosticCheck::new(
+            "rate_limit",
+            "GitHub Rate Limits",
+            CheckStatus::Warning,
+            AppError::from_gh_spawn(e).to_string(),
+        )),
     }
-    eprintln!("{}", msg);
+    checks
 }
 
+/// Check gh, storage, notifications and shortcuts, for when something
+/// (usually PR loading) silently isn't working
 #[tauri::command]
-pub async fn run_code_review(app_handle: tauri::AppHandle, url: String) -> Result<(), String> {
-    log_debug(&format!("[Rust] run_code_review called with url: {}", url));
+pub async fn run_diagnostics(app_handle: tauri::AppHandle) -> Result<DiagnosticsReport, AppError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut checks = gh_checks();
+        checks.extend(diagnostics::storage_checks());
+        checks.push(diagnostics::check_notifications(&app_handle));
+        checks.push(diagnostics::check_shortcuts(&shortcuts::statuses()));
+
+        for check in checks.iter().filter(|c| c.status != CheckStatus::Ok) {
+            logging::warn("diagnostics", &format!("{}: {}", check.label, check.detail));
+        }
+        diagnostics::report(checks)
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Task failed: {}", e)))
+}
+
+// ============ Date Parsing ============
+
+/// Resolve a natural-language date ("next tue", "in 3 days", "eom") to a date
+#[tauri::command]
+pub fn parse_schedule(expression: String) -> Result<chrono::NaiveDate, AppError> {
+    let week_start = storage::load_data()?.settings.resolved_week_start();
+    let today = chrono::Local::now().date_naive();
+
+    dates::parse_date_expression(&expression, today, week_start)
+        .ok_or_else(|| AppError::InvalidInput(format!("Couldn't understand date: {}", expression)))
+}
+
+#[tauri::command]
+pub async fn run_code_review(app_handle: tauri::AppHandle, url: String) -> Result<(), AppError> {
+    logging::debug("code_review", &format!("run_code_review called with url: {}", url));
 
     // Extract repo name and PR number from URL (e.g., https://github.com/owner/repo/pull/123)
     let parts: Vec<&str> = url.split('/').collect();
@@ -300,7 +854,7 @@ pub async fn run_code_review(app_handle: tauri::AppHandle, url: String) -> Resul
         ("repo", "unknown")
     };
 
-    log_debug(&format!("[Rust] Parsed repo: {}, pr: {}", repo_name, pr_number));
+    logging::debug("code_review", &format!("Parsed repo: {}, pr: {}", repo_name, pr_number));
 
     // Save to Obsidian vault for proper indexing
     let vault_path = "/Users/atulify/Documents/Obsidian/atul";
@@ -313,8 +867,6 @@ pub async fn run_code_review(app_handle: tauri::AppHandle, url: String) -> Resul
     let url_clone = url.clone();
     let output_file_clone = output_file.clone();
 
-    log_debug("[Rust] About to emit code-review::started event");
-
     // Emit started event
     let _ = app_handle.emit("code-review::started", CodeReviewStarted {
         url: url.clone(),
@@ -322,15 +874,13 @@ pub async fn run_code_review(app_handle: tauri::AppHandle, url: String) -> Resul
         pr_number: pr_number.to_string(),
     });
 
-    log_debug("[Rust] Emitted started event, about to spawn background task");
-
     // Spawn background task
     tauri::async_runtime::spawn(async move {
-        log_debug("[Rust:spawn] Background task started");
+        logging::debug("code_review", "Background task started");
 
         // Ensure output directory exists
         if let Err(e) = std::fs::create_dir_all(&output_dir) {
-            log_debug(&format!("[Rust:spawn] Failed to create output dir: {}", e));
+            logging::error("code_review", &format!("Failed to create output dir: {}", e));
             let _ = app_handle.emit("code-review::completed", CodeReviewCompleted {
                 url: url_clone,
                 output_file: output_file_clone,
@@ -340,7 +890,7 @@ pub async fn run_code_review(app_handle: tauri::AppHandle, url: String) -> Resul
             return;
         }
 
-        log_debug("[Rust:spawn] Output dir created, running devx claude command...");
+        logging::debug("code_review", "Output dir created, running devx claude command");
 
         // Run the Claude review command - use full path for production builds
         let devx_path = "/opt/dev/bin/user/devx";
@@ -352,10 +902,10 @@ pub async fn run_code_review(app_handle: tauri::AppHandle, url: String) -> Resul
 
         match &result {
             Ok(output) => {
-                log_debug(&format!("[Rust:spawn] Command completed, status: {:?}", output.status));
+                logging::info("code_review", &format!("Command completed, status: {:?}", output.status));
             }
             Err(e) => {
-                log_debug(&format!("[Rust:spawn] Command failed to execute: {}", e));
+                logging::error("code_review", &format!("Command failed to execute: {}", e));
             }
         }
 
@@ -622,7 +1172,7 @@ fn to_github_pr(item: GhPrSearchItem, approvals: Vec<PrApproval>, requested_revi
 
 /// Invalidate cache for a specific category
 #[tauri::command]
-pub fn invalidate_pr_cache(category: Option<String>) -> Result<(), String> {
+pub fn invalidate_pr_cache(category: Option<String>) -> Result<(), AppError> {
     let mut cache = PR_CACHE.write();
     match category.as_deref() {
         Some("high") => cache.high_priority = None,
@@ -646,7 +1196,7 @@ pub fn invalidate_pr_cache(category: Option<String>) -> Result<(), String> {
 
 /// Fetch high priority PRs: PRs with 1 approval where I'm assigned as reviewer and I haven't approved
 #[tauri::command]
-pub async fn fetch_high_priority_prs(force_refresh: Option<bool>) -> Result<Vec<GitHubPr>, String> {
+pub async fn fetch_high_priority_prs(force_refresh: Option<bool>) -> Result<Vec<GitHubPr>, AppError> {
     // Check cache first
     if !force_refresh.unwrap_or(false) {
         let cache = PR_CACHE.read();
@@ -668,11 +1218,11 @@ pub async fn fetch_high_priority_prs(force_refresh: Option<bool>) -> Result<Vec<
                 "--limit", "50",
             ])
             .output()
-            .map_err(|e| format!("Failed to run gh command: {}", e))?;
+            .map_err(AppError::from_gh_spawn)?;
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(format!("Failed to search PRs: {}", stderr));
+            return Err(AppError::from_gh_stderr("Failed to search PRs", &stderr));
         }
 
         let json_str = String::from_utf8_lossy(&output.stdout);
@@ -723,7 +1273,7 @@ pub async fn fetch_high_priority_prs(force_refresh: Option<bool>) -> Result<Vec<
 
 /// Fetch medium priority PRs: PRs with 1 approval assigned to team slug
 #[tauri::command]
-pub async fn fetch_medium_priority_prs(force_refresh: Option<bool>) -> Result<Vec<GitHubPr>, String> {
+pub async fn fetch_medium_priority_prs(force_refresh: Option<bool>) -> Result<Vec<GitHubPr>, AppError> {
     // Check cache first
     if !force_refresh.unwrap_or(false) {
         let cache = PR_CACHE.read();
@@ -745,11 +1295,11 @@ pub async fn fetch_medium_priority_prs(force_refresh: Option<bool>) -> Result<Ve
                 "--limit", "50",
             ])
             .output()
-            .map_err(|e| format!("Failed to run gh command: {}", e))?;
+            .map_err(AppError::from_gh_spawn)?;
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(format!("Failed to search PRs: {}", stderr));
+            return Err(AppError::from_gh_stderr("Failed to search PRs", &stderr));
         }
 
         let json_str = String::from_utf8_lossy(&output.stdout);
@@ -798,7 +1348,7 @@ pub async fn fetch_medium_priority_prs(force_refresh: Option<bool>) -> Result<Ve
 
 /// Fetch low priority PRs: PRs with 0 approvals assigned to me or team
 #[tauri::command]
-pub async fn fetch_low_priority_prs(force_refresh: Option<bool>) -> Result<Vec<GitHubPr>, String> {
+pub async fn fetch_low_priority_prs(force_refresh: Option<bool>) -> Result<Vec<GitHubPr>, AppError> {
     // Check cache first
     if !force_refresh.unwrap_or(false) {
         let cache = PR_CACHE.read();
@@ -824,7 +1374,7 @@ pub async fn fetch_low_priority_prs(force_refresh: Option<bool>) -> Result<Vec<G
                 "--limit", "50",
             ])
             .output()
-            .map_err(|e| format!("Failed to run gh command: {}", e))?;
+            .map_err(AppError::from_gh_spawn)?;
 
         if output1.status.success() {
             let json_str = String::from_utf8_lossy(&output1.stdout);
@@ -851,7 +1401,7 @@ pub async fn fetch_low_priority_prs(force_refresh: Option<bool>) -> Result<Vec<G
                 "--limit", "50",
             ])
             .output()
-            .map_err(|e| format!("Failed to run gh command: {}", e))?;
+            .map_err(AppError::from_gh_spawn)?;
 
         if output2.status.success() {
             let json_str = String::from_utf8_lossy(&output2.stdout);
@@ -903,7 +1453,7 @@ pub async fn fetch_low_priority_prs(force_refresh: Option<bool>) -> Result<Vec<G
 
 /// Fetch my PRs that have at least 1 approval
 #[tauri::command]
-pub async fn fetch_my_approved_prs(force_refresh: Option<bool>) -> Result<Vec<GitHubPr>, String> {
+pub async fn fetch_my_approved_prs(force_refresh: Option<bool>) -> Result<Vec<GitHubPr>, AppError> {
     // Check cache first
     if !force_refresh.unwrap_or(false) {
         let cache = PR_CACHE.read();
@@ -925,11 +1475,11 @@ pub async fn fetch_my_approved_prs(force_refresh: Option<bool>) -> Result<Vec<Gi
                 "--limit", "50",
             ])
             .output()
-            .map_err(|e| format!("Failed to run gh command: {}", e))?;
+            .map_err(AppError::from_gh_spawn)?;
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(format!("Failed to search PRs: {}", stderr));
+            return Err(AppError::from_gh_stderr("Failed to search PRs", &stderr));
         }
 
         let json_str = String::from_utf8_lossy(&output.stdout);
@@ -972,7 +1522,7 @@ pub async fn fetch_my_approved_prs(force_refresh: Option<bool>) -> Result<Vec<Gi
 
 /// Fetch my PRs that have changes requested
 #[tauri::command]
-pub async fn fetch_my_changes_requested_prs(force_refresh: Option<bool>) -> Result<Vec<GitHubPr>, String> {
+pub async fn fetch_my_changes_requested_prs(force_refresh: Option<bool>) -> Result<Vec<GitHubPr>, AppError> {
     // Check cache first
     if !force_refresh.unwrap_or(false) {
         let cache = PR_CACHE.read();
@@ -995,11 +1545,11 @@ pub async fn fetch_my_changes_requested_prs(force_refresh: Option<bool>) -> Resu
                 "--limit", "50",
             ])
             .output()
-            .map_err(|e| format!("Failed to run gh command: {}", e))?;
+            .map_err(AppError::from_gh_spawn)?;
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(format!("Failed to search PRs: {}", stderr));
+            return Err(AppError::from_gh_stderr("Failed to search PRs", &stderr));
         }
 
         let json_str = String::from_utf8_lossy(&output.stdout);
@@ -1039,7 +1589,7 @@ pub async fn fetch_my_changes_requested_prs(force_refresh: Option<bool>) -> Resu
 
 /// Fetch my PRs that need reviews (0 approvals, no changes requested)
 #[tauri::command]
-pub async fn fetch_my_needs_review_prs(force_refresh: Option<bool>) -> Result<Vec<GitHubPr>, String> {
+pub async fn fetch_my_needs_review_prs(force_refresh: Option<bool>) -> Result<Vec<GitHubPr>, AppError> {
     // Check cache first
     if !force_refresh.unwrap_or(false) {
         let cache = PR_CACHE.read();
@@ -1061,11 +1611,11 @@ pub async fn fetch_my_needs_review_prs(force_refresh: Option<bool>) -> Result<Ve
                 "--limit", "50",
             ])
             .output()
-            .map_err(|e| format!("Failed to run gh command: {}", e))?;
+            .map_err(AppError::from_gh_spawn)?;
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(format!("Failed to search PRs: {}", stderr));
+            return Err(AppError::from_gh_stderr("Failed to search PRs", &stderr));
         }
 
         let json_str = String::from_utf8_lossy(&output.stdout);
@@ -1118,172 +1668,537 @@ pub struct GitHubStats {
     pub prs_approved_prev_3_months: u32,
 }
 
-fn get_date_ranges() -> (String, String, String, String, String) {
-    use chrono::{Datelike, Duration, Local, NaiveDate};
+#[derive(Debug, Deserialize)]
+struct GraphQlSearchResponse<T> {
+    data: Option<HashMap<String, GraphQlSearch<T>>>,
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
 
-    let today = Local::now().date_naive();
-    let first_of_month = NaiveDate::from_ymd_opt(today.year(), today.month(), 1).unwrap();
+#[derive(Debug, Deserialize)]
+struct GraphQlError {
+    message: String,
+}
 
-    let prev_month = if today.month() == 1 {
-        NaiveDate::from_ymd_opt(today.year() - 1, 12, 1).unwrap()
-    } else {
-        NaiveDate::from_ymd_opt(today.year(), today.month() - 1, 1).unwrap()
+#[derive(Debug, Deserialize)]
+struct GraphQlSearch<T> {
+    #[serde(rename = "pageInfo")]
+    page_info: GraphQlPageInfo,
+    nodes: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlPageInfo {
+    #[serde(rename = "hasNextPage")]
+    has_next_page: bool,
+    #[serde(rename = "endCursor")]
+    end_cursor: Option<String>,
+}
+
+/// Run several PR searches as aliases of one GraphQL query, paging them
+/// together until each runs out (GitHub caps a search at 1000 results).
+/// `fields` is the selection applied to each `PullRequest` node.
+fn paged_pr_search<T: serde::de::DeserializeOwned>(
+    searches: &[(&str, String)],
+    fields: &str,
+    page_size: u32,
+) -> Result<HashMap<String, Vec<T>>, AppError> {
+    let gh_path = get_gh_path()?;
+    let mut results: HashMap<String, Vec<T>> = HashMap::new();
+    // Cursor for each search still being paged; None before the first page
+    let mut pending: Vec<(&str, &String, Option<String>)> =
+        searches.iter().map(|(alias, q)| (*alias, q, None)).collect();
+
+    while !pending.is_empty() {
+        let fragments: Vec<String> = pending
+            .iter()
+            .map(|(alias, q, cursor)| {
+                let after = cursor
+                    .as_ref()
+                    .map(|c| format!(", after: {}", serde_json::Value::from(c.as_str())))
+                    .unwrap_or_default();
+                format!(
+                    r#"{alias}: search(query: {q}, type: ISSUE, first: {page_size}{after}) {{
+                        pageInfo {{ hasNextPage endCursor }}
+                        nodes {{ ... on PullRequest {{ {fields} }} }}
+                    }}"#,
+                    alias = alias,
+                    q = serde_json::Value::from(q.as_str()),
+                    page_size = page_size,
+                    after = after,
+                    fields = fields
+                )
+            })
+            .collect();
+        let query = format!("query {{ {} }}", fragments.join("\n"));
+
+        let output = Command::new(gh_path)
+            .args(["api", "graphql", "-f", &format!("query={}", query)])
+            .output()
+            .map_err(AppError::from_gh_spawn)?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(AppError::from_gh_stderr("Failed to search PRs", &stderr));
+        }
+
+        let json_str = String::from_utf8_lossy(&output.stdout);
+        let response: GraphQlSearchResponse<T> = serde_json::from_str(&json_str)
+            .map_err(|e| format!("Failed to parse PR search JSON: {}", e))?;
+        if let Some(error) = response.errors.first() {
+            return Err(AppError::from_gh_stderr("Failed to search PRs", &error.message));
+        }
+        let mut data = response.data.unwrap_or_default();
+
+        let mut next = Vec::new();
+        for (alias, q, _) in pending {
+            let search = data
+                .remove(alias)
+                .ok_or_else(|| format!("PR search response is missing '{}'", alias))?;
+            results.entry(alias.to_string()).or_default().extend(search.nodes);
+            if search.page_info.has_next_page {
+                if let Some(cursor) = search.page_info.end_cursor {
+                    next.push((alias, q, Some(cursor)));
+                }
+            }
+        }
+        pending = next;
+    }
+
+    Ok(results)
+}
+
+/// Search nodes are empty objects for anything that isn't a PR
+#[derive(Debug, Deserialize)]
+struct GraphQlStatsPr {
+    number: Option<u64>,
+    title: Option<String>,
+    url: Option<String>,
+    #[serde(rename = "mergedAt")]
+    merged_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Fetch PRs merged on or after `from` that I authored and that I reviewed
+fn fetch_stats_prs(from: chrono::NaiveDate) -> Result<(Vec<github_stats::StatsPr>, Vec<github_stats::StatsPr>), AppError> {
+    let base = format!("repo:{} is:pr is:merged merged:>={}", REPO, from.format("%Y-%m-%d"));
+    let searches = [
+        ("merged", format!("{} author:{}", base, USER)),
+        ("reviewed", format!("{} reviewed-by:{} -author:{}", base, USER, USER)),
+    ];
+    let mut results = paged_pr_search::<GraphQlStatsPr>(&searches, "number title url mergedAt", 100)?;
+
+    let mut take = |alias: &str| -> Vec<github_stats::StatsPr> {
+        results
+            .remove(alias)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|node| {
+                Some(github_stats::StatsPr {
+                    number: node.number?,
+                    title: node.title.unwrap_or_default(),
+                    url: node.url.unwrap_or_default(),
+                    merged_at: node.merged_at?,
+                })
+            })
+            .collect()
     };
-    let prev_month_end = first_of_month - Duration::days(1);
+    Ok((take("merged"), take("reviewed")))
+}
+
+/// Serializes syncs so concurrent requests don't both fetch and overwrite the cache
+static STATS_SYNC: Lazy<parking_lot::Mutex<()>> = Lazy::new(|| parking_lot::Mutex::new(()));
+
+/// Load the on-disk cache, fetching whatever is needed to cover `start` through today.
+/// `force_refresh` ignores how recently it was synced.
+fn synced_stats_cache(start: chrono::NaiveDate, force_refresh: bool) -> Result<github_stats::GitHubStatsCache, AppError> {
+    let _guard = STATS_SYNC.lock();
+    let now = chrono::Utc::now();
+    let mut cache = github_stats::load_cache();
+
+    if let Some(from) = github_stats::sync_from(&cache, start, now, force_refresh) {
+        let (merged, reviewed) = fetch_stats_prs(from)?;
+        github_stats::merge(&mut cache, from, merged, reviewed, now);
+        if let Err(e) = github_stats::save_cache(&cache) {
+            logging::warn("github", &format!("Failed to save GitHub stats cache: {}", e));
+        }
+    }
+    Ok(cache)
+}
+
+#[tauri::command]
+pub async fn fetch_github_stats() -> Result<GitHubStats, AppError> {
+    tauri::async_runtime::spawn_blocking(|| {
+        use chrono::{Datelike, Duration, Local};
+
+        let today = Local::now().date_naive();
+        let first_of_month = today.with_day(1).unwrap_or(today);
+        let prev_month_end = first_of_month - Duration::days(1);
+        let prev_month_start = prev_month_end.with_day(1).unwrap_or(prev_month_end);
+        let three_months_start = today - Duration::days(90);
 
-    let three_months_ago = today - Duration::days(90);
+        let cache = synced_stats_cache(three_months_start, false)?;
+        let count = github_stats::count;
 
-    (
-        first_of_month.format("%Y-%m-%d").to_string(),
-        prev_month.format("%Y-%m-%d").to_string(),
-        prev_month_end.format("%Y-%m-%d").to_string(),
-        three_months_ago.format("%Y-%m-%d").to_string(),
-        today.format("%Y-%m-%d").to_string(),
-    )
+        Ok(GitHubStats {
+            prs_merged_mtd: count(&cache.merged, first_of_month, today),
+            prs_merged_prev_month: count(&cache.merged, prev_month_start, prev_month_end),
+            prs_merged_prev_3_months: count(&cache.merged, three_months_start, today),
+            prs_approved_mtd: count(&cache.reviewed, first_of_month, today),
+            prs_approved_prev_month: count(&cache.reviewed, prev_month_start, prev_month_end),
+            prs_approved_prev_3_months: count(&cache.reviewed, three_months_start, today),
+        })
+    })
+    .await
+    .map_err(|e| format!("Task failed: {}", e))?
 }
 
-fn count_prs_from_output(output: &std::process::Output) -> u32 {
+/// Merged and reviewed PR counts for any date range, with a weekly or monthly series
+#[tauri::command]
+pub async fn get_github_stats(
+    start: chrono::NaiveDate,
+    end: Option<chrono::NaiveDate>,
+    granularity: Option<github_stats::Granularity>,
+    force_refresh: Option<bool>,
+) -> Result<github_stats::GitHubRangeStats, AppError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let end = end.unwrap_or_else(|| chrono::Local::now().date_naive());
+        if end < start {
+            return Err(AppError::InvalidInput("End date is before start date".to_string()));
+        }
+
+        let week_start = storage::load_data()?.settings.resolved_week_start();
+        let cache = synced_stats_cache(start, force_refresh.unwrap_or(false))?;
+        Ok(github_stats::range_stats(
+            &cache,
+            start,
+            end,
+            granularity.unwrap_or(github_stats::Granularity::Week),
+            week_start,
+        ))
+    })
+    .await
+    .map_err(|e| format!("Task failed: {}", e))?
+}
+
+// ============ Review Turnaround ============
+
+#[derive(Debug, Deserialize)]
+struct GraphQlTurnaroundPr {
+    number: Option<u64>,
+    title: Option<String>,
+    url: Option<String>,
+    state: Option<String>,
+    #[serde(rename = "timelineItems")]
+    timeline_items: Option<GraphQlNodes<GraphQlReviewRequestedEvent>>,
+    reviews: Option<GraphQlNodes<GraphQlReviewNode>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlNodes<T> {
+    nodes: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlReviewRequestedEvent {
+    #[serde(rename = "createdAt")]
+    created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(rename = "requestedReviewer")]
+    requested_reviewer: Option<GraphQlReviewer>,
+}
+
+/// Either field may be missing, e.g. for bots and mannequins
+#[derive(Debug, Deserialize)]
+struct GraphQlReviewer {
+    login: Option<String>,
+    slug: Option<String>,
+}
+
+/// PRs I was asked to review (directly or via my team) with activity since `from`
+fn fetch_requested_prs(from: chrono::NaiveDate) -> Result<Vec<github_stats::RequestedPr>, AppError> {
+    let base = format!("repo:{} is:pr -author:{} updated:>={}", REPO, USER, from.format("%Y-%m-%d"));
+    let searches = [
+        ("reviewed", format!("{} reviewed-by:{}", base, USER)),
+        ("requested", format!("{} review-requested:{}", base, USER)),
+        ("team", format!("{} team-review-requested:{}", base, TEAM_SLUG)),
+    ];
+    let fields = format!(
+        r#"number title url state
+        timelineItems(itemTypes: [REVIEW_REQUESTED_EVENT], first: 50) {{
+            nodes {{
+                ... on ReviewRequestedEvent {{
+                    createdAt
+                    requestedReviewer {{
+                        ... on User {{ login }}
+                        ... on Team {{ slug }}
+                    }}
+                }}
+            }}
+        }}
+        reviews(first: 50, author: "{user}") {{
+            nodes {{ state author {{ login }} submittedAt }}
+        }}"#,
+        user = USER
+    );
+    let results = paged_pr_search::<GraphQlTurnaroundPr>(&searches, &fields, 25)?;
+
+    let team = TEAM_SLUG.rsplit('/').next().unwrap_or(TEAM_SLUG);
+    let prs = results
+        .into_values()
+        .flatten()
+        .filter_map(|node| {
+            let requested_at = node
+                .timeline_items
+                .map(|t| t.nodes)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|event| {
+                    event.requested_reviewer.as_ref().is_some_and(|r| {
+                        r.login.as_deref().is_some_and(|l| l.eq_ignore_ascii_case(USER))
+                            || r.slug.as_deref().is_some_and(|s| s.eq_ignore_ascii_case(team))
+                    })
+                })
+                .filter_map(|event| event.created_at)
+                .collect();
+            // Pending (unsubmitted) reviews have no submittedAt
+            let reviewed_at = node
+                .reviews
+                .map(|r| r.nodes)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|review| review.state != "PENDING")
+                .filter_map(|review| review.submitted_at)
+                .filter_map(|at| chrono::DateTime::parse_from_rfc3339(&at).ok())
+                .map(|at| at.with_timezone(&chrono::Utc))
+                .collect();
+
+            Some(github_stats::RequestedPr {
+                number: node.number?,
+                title: node.title.unwrap_or_default(),
+                url: node.url.unwrap_or_default(),
+                state: node.state.unwrap_or_default(),
+                requested_at,
+                reviewed_at,
+            })
+        })
+        .collect();
+    Ok(prs)
+}
+
+/// Median/p90 time to my first review per week, and requests I never reviewed.
+/// Defaults to the last 12 weeks.
+#[tauri::command]
+pub async fn get_review_turnaround(
+    start: Option<chrono::NaiveDate>,
+    end: Option<chrono::NaiveDate>,
+) -> Result<github_stats::ReviewTurnaround, AppError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let today = chrono::Local::now().date_naive();
+        let end = end.unwrap_or(today);
+        let start = start.unwrap_or(end - chrono::Duration::weeks(12) + chrono::Duration::days(1));
+        if end < start {
+            return Err(AppError::InvalidInput("End date is before start date".to_string()));
+        }
+
+        let week_start = storage::load_data()?.settings.resolved_week_start();
+        let prs = fetch_requested_prs(start)?;
+        Ok(github_stats::turnaround(&prs, start, end, week_start))
+    })
+    .await
+    .map_err(|e| format!("Task failed: {}", e))?
+}
+
+// ============ Brag Entry Suggestions ============
+
+#[derive(Debug, Deserialize)]
+struct GhMergedPrItem {
+    number: u64,
+    title: String,
+    url: String,
+    #[serde(rename = "closedAt")]
+    closed_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// PRs authored by USER and merged between `start` and `end` (inclusive).
+/// Search results don't expose mergedAt, but a merged PR closes when it merges.
+fn fetch_merged_prs(start: chrono::NaiveDate, end: chrono::NaiveDate) -> Result<Vec<brag::MergedPr>, AppError> {
+    let gh_path = get_gh_path()?;
+    let output = Command::new(gh_path)
+        .args([
+            "search", "prs",
+            "--repo", REPO,
+            "--author", USER,
+            "--merged-at", &format!("{}..{}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d")),
+            "--json", "number,title,url,closedAt",
+            "--limit", "200",
+        ])
+        .output()
+        .map_err(AppError::from_gh_spawn)?;
+
     if !output.status.success() {
-        return 0;
-    }
-    let json_str = String::from_utf8_lossy(&output.stdout);
-    if let Ok(prs) = serde_json::from_str::<Vec<serde_json::Value>>(&json_str) {
-        prs.len() as u32
-    } else {
-        0
+        return Err(AppError::from_gh_stderr("gh search failed", &String::from_utf8_lossy(&output.stderr)));
     }
+
+    let items: Vec<GhMergedPrItem> =
+        serde_json::from_slice(&output.stdout).map_err(|e| format!("Failed to parse merged PRs: {}", e))?;
+
+    Ok(items
+        .into_iter()
+        .map(|item| brag::MergedPr {
+            number: item.number,
+            title: item.title,
+            url: item.url,
+            merged_on: item.closed_at.with_timezone(&chrono::Local).date_naive(),
+        })
+        .collect())
 }
 
+/// Propose draft entries for a brag doc from merged PRs and completed tasks in
+/// its date range. Drafts are not saved; the caller adds the ones it keeps.
+/// If GitHub can't be reached, drafts from completed tasks are still returned.
 #[tauri::command]
-pub async fn fetch_github_stats() -> Result<GitHubStats, String> {
-    tauri::async_runtime::spawn_blocking(|| {
-        let gh_path = get_gh_path()?;
-        let (mtd_start, prev_month_start, prev_month_end, three_months_start, _today) = get_date_ranges();
-
-        // Run all 6 queries in parallel using thread scope
-        let (
-            prs_merged_mtd,
-            prs_merged_prev_month,
-            prs_merged_prev_3_months,
-            prs_approved_mtd,
-            prs_approved_prev_month,
-            prs_approved_prev_3_months,
-        ) = std::thread::scope(|s| {
-            let h1 = s.spawn(|| {
-                Command::new(gh_path)
-                    .args([
-                        "search", "prs",
-                        "--repo", REPO,
-                        "--author", USER,
-                        "--merged",
-                        "--merged", &format!(">={}", mtd_start),
-                        "--json", "number",
-                        "--limit", "200",
-                    ])
-                    .output()
-                    .map(|o| count_prs_from_output(&o))
-                    .unwrap_or(0)
-            });
+pub async fn suggest_brag_entries(doc_id: String) -> Result<Vec<BragEntry>, AppError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let data = trash::visible(&storage::load_data()?);
+        let doc = data
+            .brag_docs
+            .iter()
+            .find(|d| d.id == doc_id)
+            .ok_or_else(|| AppError::NotFound(format!("Brag doc '{}' not found", doc_id)))?;
+
+        let merged_prs = fetch_merged_prs(doc.start_date, doc.end_date).unwrap_or_else(|e| {
+            logging::warn("github", &format!("suggest_brag_entries: skipping merged PRs: {}", e));
+            Vec::new()
+        });
 
-            let h2 = s.spawn(|| {
-                Command::new(gh_path)
-                    .args([
-                        "search", "prs",
-                        "--repo", REPO,
-                        "--author", USER,
-                        "--merged",
-                        "--merged", &format!("{}..{}", prev_month_start, prev_month_end),
-                        "--json", "number",
-                        "--limit", "200",
-                    ])
-                    .output()
-                    .map(|o| count_prs_from_output(&o))
-                    .unwrap_or(0)
-            });
+        Ok(brag::draft_entries(doc, &merged_prs, &data.tasks))
+    })
+    .await
+    .map_err(|e| format!("Task failed: {}", e))?
+}
 
-            let h3 = s.spawn(|| {
-                Command::new(gh_path)
-                    .args([
-                        "search", "prs",
-                        "--repo", REPO,
-                        "--author", USER,
-                        "--merged",
-                        "--merged", &format!(">={}", three_months_start),
-                        "--json", "number",
-                        "--limit", "200",
-                    ])
-                    .output()
-                    .map(|o| count_prs_from_output(&o))
-                    .unwrap_or(0)
-            });
+// ============ Weekly Review ============
 
-            let h4 = s.spawn(|| {
-                Command::new(gh_path)
-                    .args([
-                        "search", "prs",
-                        "--repo", REPO,
-                        "--reviewed-by", USER,
-                        "--merged",
-                        "--merged", &format!(">={}", mtd_start),
-                        "--json", "number",
-                        "--limit", "200",
-                    ])
-                    .output()
-                    .map(|o| count_prs_from_output(&o))
-                    .unwrap_or(0)
-            });
+/// PRs by other authors that USER reviewed and that were updated between
+/// `start` and `end`. Search can't filter on review date, so activity on the
+/// PR stands in for it.
+fn fetch_reviewed_prs(start: chrono::NaiveDate, end: chrono::NaiveDate) -> Result<Vec<review::ReviewPr>, AppError> {
+    let gh_path = get_gh_path()?;
+    let output = Command::new(gh_path)
+        .args([
+            "search", "prs",
+            "--repo", REPO,
+            "--reviewed-by", USER,
+            "--updated", &format!("{}..{}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d")),
+            "--json", "number,title,url,author,createdAt",
+            "--limit", "200",
+        ])
+        .output()
+        .map_err(AppError::from_gh_spawn)?;
 
-            let h5 = s.spawn(|| {
-                Command::new(gh_path)
-                    .args([
-                        "search", "prs",
-                        "--repo", REPO,
-                        "--reviewed-by", USER,
-                        "--merged",
-                        "--merged", &format!("{}..{}", prev_month_start, prev_month_end),
-                        "--json", "number",
-                        "--limit", "200",
-                    ])
-                    .output()
-                    .map(|o| count_prs_from_output(&o))
-                    .unwrap_or(0)
-            });
+    if !output.status.success() {
+        return Err(AppError::from_gh_stderr("gh search failed", &String::from_utf8_lossy(&output.stderr)));
+    }
 
-            let h6 = s.spawn(|| {
-                Command::new(gh_path)
-                    .args([
-                        "search", "prs",
-                        "--repo", REPO,
-                        "--reviewed-by", USER,
-                        "--merged",
-                        "--merged", &format!(">={}", three_months_start),
-                        "--json", "number",
-                        "--limit", "200",
-                    ])
-                    .output()
-                    .map(|o| count_prs_from_output(&o))
-                    .unwrap_or(0)
-            });
+    let items: Vec<GhPrSearchItem> =
+        serde_json::from_slice(&output.stdout).map_err(|e| format!("Failed to parse reviewed PRs: {}", e))?;
 
-            (
-                h1.join().unwrap_or(0),
-                h2.join().unwrap_or(0),
-                h3.join().unwrap_or(0),
-                h4.join().unwrap_or(0),
-                h5.join().unwrap_or(0),
-                h6.join().unwrap_or(0),
-            )
+    Ok(items
+        .into_iter()
+        .filter(|item| item.author.login != USER)
+        .map(|item| review::ReviewPr {
+            number: item.number,
+            title: item.title,
+            url: item.url,
+        })
+        .collect())
+}
+
+/// Summarize the week containing `date` (default: today)
+#[tauri::command]
+pub async fn generate_weekly_review(date: Option<chrono::NaiveDate>) -> Result<review::WeeklyReview, AppError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let data = trash::visible(&storage::load_data()?);
+        let date = date.unwrap_or_else(|| chrono::Local::now().date_naive());
+        let week_start = dates::start_of_week(date, data.settings.resolved_week_start());
+        let week_end = week_start + chrono::Duration::days(6);
+
+        let prs = fetch_merged_prs(week_start, week_end).and_then(|merged| {
+            let merged = merged
+                .into_iter()
+                .map(|pr| review::ReviewPr {
+                    number: pr.number,
+                    title: pr.title,
+                    url: pr.url,
+                })
+                .collect();
+            Ok((merged, fetch_reviewed_prs(week_start, week_end)?))
         });
 
-        Ok(GitHubStats {
-            prs_merged_mtd,
-            prs_merged_prev_month,
-            prs_merged_prev_3_months,
-            prs_approved_mtd,
-            prs_approved_prev_month,
-            prs_approved_prev_3_months,
+        Ok(match prs {
+            Ok((merged, reviewed)) => review::build(&data, week_start, merged, reviewed, None),
+            Err(e) => {
+                logging::warn("github", &format!("generate_weekly_review: skipping PRs: {}", e));
+                review::build(&data, week_start, Vec::new(), Vec::new(), Some(e.to_string()))
+            }
         })
     })
     .await
     .map_err(|e| format!("Task failed: {}", e))?
 }
+
+/// Save a rendered review as a new note and return its id
+#[tauri::command]
+pub fn save_weekly_review_note(app_handle: tauri::AppHandle, markdown: String) -> Result<String, AppError> {
+    let mut data = storage::load_data()?;
+    let before = data.clone();
+    let note = Note::new(markdown);
+    let id = note.id.clone();
+    data.notes.push(note);
+    storage::save_data(&data)?;
+    history::record(&app_handle, None, &before, &data);
+
+    let _ = app_handle.emit("data::changed", ());
+    Ok(id)
+}
+
+/// Create (or re-enable) the weekly reminder that saves a review note every
+/// Friday at `time` (HH:MM, default 16:00)
+#[tauri::command]
+pub fn schedule_weekly_review_reminder(
+    app_handle: tauri::AppHandle,
+    time: Option<String>,
+) -> Result<Notification, AppError> {
+    let mut data = storage::load_data()?;
+    let before = data.clone();
+    let time = time.unwrap_or_else(|| "16:00".to_string());
+
+    let existing = data
+        .notifications
+        .iter_mut()
+        .find(|n| n.action == Some(NotificationAction::WeeklyReview));
+    let reminder = match existing {
+        Some(reminder) => {
+            reminder.time = time;
+            reminder.enabled = true;
+            reminder.deleted_at = None;
+            reminder.clone()
+        }
+        None => {
+            let mut reminder = Notification::new(
+                "Weekly review".to_string(),
+                "Your weekly review has been saved to Notes".to_string(),
+                ScheduleType::Weekly,
+                time,
+            );
+            reminder.day_of_week = Some(5);
+            reminder.action = Some(NotificationAction::WeeklyReview);
+            data.notifications.push(reminder.clone());
+            reminder
+        }
+    };
+    storage::save_data(&data)?;
+    history::record(&app_handle, None, &before, &data);
+
+    let _ = app_handle.emit("data::changed", ());
+    Ok(reminder)
+}
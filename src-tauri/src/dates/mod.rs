@@ -0,0 +1,353 @@
+use chrono::{Datelike, Duration, Months, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+// ============ Natural-Language Date Parsing ============
+//
+// Supported expressions (case-insensitive, relative to a given "today"):
+//   today, tomorrow, yesterday (plus tod / tmr / tmrw)
+//   mon, tuesday, ...       next occurrence after today
+//   this fri                that day within the current week
+//   next tue                that day within next week
+//   next week / next month  first day of next week / month
+//   in 3 days, in 2 weeks, in a month, 3d, 2w
+//   eow / end of week       last day of the current week
+//   eom / end of month      last day of the current month
+//   eoy / end of year       December 31st of the current year
+//   2025-01-31, jan 31, 31 january
+
+/// First day of the week, used for "this"/"next" weekday and end-of-week
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WeekStart {
+    Monday,
+    Sunday,
+    Saturday,
+}
+
+impl WeekStart {
+    /// Guess the week start from a POSIX locale string such as "en_US.UTF-8"
+    pub fn from_locale(locale: &str) -> Self {
+        let region = locale
+            .split(['.', '@'])
+            .next()
+            .and_then(|l| l.split(['_', '-']).nth(1))
+            .unwrap_or("")
+            .to_uppercase();
+
+        match region.as_str() {
+            "US" | "CA" | "MX" | "BR" | "JP" | "KR" | "TW" | "HK" | "IL" | "PH" | "IN" | "ZA" | "AU" => {
+                Self::Sunday
+            }
+            "AE" | "AF" | "BH" | "DZ" | "EG" | "IQ" | "IR" | "JO" | "KW" | "LY" | "OM" | "QA" | "SA"
+            | "SD" | "SY" => Self::Saturday,
+            _ => Self::Monday,
+        }
+    }
+
+    /// Week start for the current process, read from the usual locale env vars
+    pub fn from_system() -> Self {
+        ["LC_ALL", "LC_TIME", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty() && value != "C" && value != "POSIX")
+            .map(|locale| Self::from_locale(&locale))
+            .unwrap_or(Self::Monday)
+    }
+
+    fn weekday(self) -> Weekday {
+        match self {
+            Self::Monday => Weekday::Mon,
+            Self::Sunday => Weekday::Sun,
+            Self::Saturday => Weekday::Sat,
+        }
+    }
+}
+
+fn parse_weekday(word: &str) -> Option<Weekday> {
+    match word {
+        "mon" | "monday" => Some(Weekday::Mon),
+        "tue" | "tues" | "tuesday" => Some(Weekday::Tue),
+        "wed" | "weds" | "wednesday" => Some(Weekday::Wed),
+        "thu" | "thur" | "thurs" | "thursday" => Some(Weekday::Thu),
+        "fri" | "friday" => Some(Weekday::Fri),
+        "sat" | "saturday" => Some(Weekday::Sat),
+        "sun" | "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn parse_month(word: &str) -> Option<u32> {
+    let month = match word {
+        "jan" | "january" => 1,
+        "feb" | "february" => 2,
+        "mar" | "march" => 3,
+        "apr" | "april" => 4,
+        "may" => 5,
+        "jun" | "june" => 6,
+        "jul" | "july" => 7,
+        "aug" | "august" => 8,
+        "sep" | "sept" | "september" => 9,
+        "oct" | "october" => 10,
+        "nov" | "november" => 11,
+        "dec" | "december" => 12,
+        _ => return None,
+    };
+    Some(month)
+}
+
+/// First day of the week containing `date`
+pub fn start_of_week(date: NaiveDate, week_start: WeekStart) -> NaiveDate {
+    let offset = (7 + date.weekday().num_days_from_monday() - week_start.weekday().num_days_from_monday()) % 7;
+    date - Duration::days(offset as i64)
+}
+
+fn end_of_month(date: NaiveDate) -> NaiveDate {
+    let first = date.with_day(1).unwrap_or(date);
+    first + Months::new(1) - Duration::days(1)
+}
+
+/// `weekday` within the week that starts at `week_first_day`
+fn weekday_in_week(week_first_day: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let offset = (7 + weekday.num_days_from_monday() - week_first_day.weekday().num_days_from_monday()) % 7;
+    week_first_day + Duration::days(offset as i64)
+}
+
+/// Next `weekday` strictly after `today`
+fn next_weekday(today: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let mut date = today + Duration::days(1);
+    while date.weekday() != weekday {
+        date += Duration::days(1);
+    }
+    date
+}
+
+/// Parse "3 days", "2w", "a month" into a date offset from `today`
+fn parse_offset(words: &[&str], today: NaiveDate) -> Option<NaiveDate> {
+    let (amount, unit) = match words {
+        [amount, unit] => (*amount, *unit),
+        // Compact form: "3d", "2w", "1m"
+        [compact] => {
+            let split = compact.find(|c: char| !c.is_ascii_digit())?;
+            compact.split_at(split)
+        }
+        _ => return None,
+    };
+
+    let amount: u32 = match amount {
+        "a" | "an" | "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        n => n.parse().ok()?,
+    };
+
+    match unit {
+        "d" | "day" | "days" => today.checked_add_signed(Duration::days(amount as i64)),
+        "w" | "wk" | "wks" | "week" | "weeks" => today.checked_add_signed(Duration::weeks(amount as i64)),
+        "m" | "mo" | "month" | "months" => today.checked_add_months(Months::new(amount)),
+        _ => None,
+    }
+}
+
+/// Parse "jan 31" / "31 jan", rolling over to next year if the date has passed
+fn parse_month_day(words: &[&str], today: NaiveDate) -> Option<NaiveDate> {
+    let (month, day) = match words {
+        [a, b] => match (parse_month(a), parse_month(b)) {
+            (Some(month), None) => (month, b.trim_end_matches(|c: char| c.is_alphabetic()).parse().ok()?),
+            (None, Some(month)) => (month, a.trim_end_matches(|c: char| c.is_alphabetic()).parse().ok()?),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let this_year = NaiveDate::from_ymd_opt(today.year(), month, day)?;
+    if this_year >= today {
+        Some(this_year)
+    } else {
+        NaiveDate::from_ymd_opt(today.year() + 1, month, day)
+    }
+}
+
+/// Parse a natural-language date expression relative to `today`
+pub fn parse_date_expression(input: &str, today: NaiveDate, week_start: WeekStart) -> Option<NaiveDate> {
+    let normalized = input.trim().to_lowercase();
+    if let Ok(date) = NaiveDate::parse_from_str(&normalized, "%Y-%m-%d") {
+        return Some(date);
+    }
+
+    let words: Vec<&str> = normalized
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    match words.as_slice() {
+        ["today"] | ["tod"] | ["now"] => Some(today),
+        ["tomorrow"] | ["tmr"] | ["tmrw"] => Some(today + Duration::days(1)),
+        ["yesterday"] => Some(today - Duration::days(1)),
+        ["eow"] | ["end", "of", "week"] | ["end", "of", "the", "week"] => {
+            Some(start_of_week(today, week_start) + Duration::days(6))
+        }
+        ["eom"] | ["end", "of", "month"] | ["end", "of", "the", "month"] => Some(end_of_month(today)),
+        ["eoy"] | ["end", "of", "year"] | ["end", "of", "the", "year"] => NaiveDate::from_ymd_opt(today.year(), 12, 31),
+        ["next", "week"] => Some(start_of_week(today, week_start) + Duration::weeks(1)),
+        ["next", "month"] => today.with_day(1).and_then(|d| d.checked_add_months(Months::new(1))),
+        ["next", day] => {
+            let weekday = parse_weekday(day)?;
+            Some(weekday_in_week(start_of_week(today, week_start) + Duration::weeks(1), weekday))
+        }
+        ["this", day] => {
+            let weekday = parse_weekday(day)?;
+            Some(weekday_in_week(start_of_week(today, week_start), weekday))
+        }
+        ["in", rest @ ..] => parse_offset(rest, today),
+        [day] if parse_weekday(day).is_some() => parse_weekday(day).map(|w| next_weekday(today, w)),
+        rest => parse_offset(rest, today).or_else(|| parse_month_day(rest, today)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Wednesday, January 15th 2025
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, 15).unwrap()
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn parse(input: &str) -> Option<NaiveDate> {
+        parse_date_expression(input, today(), WeekStart::Monday)
+    }
+
+    fn parse_sunday(input: &str) -> Option<NaiveDate> {
+        parse_date_expression(input, today(), WeekStart::Sunday)
+    }
+
+    #[test]
+    fn relative_days() {
+        assert_eq!(parse("today"), Some(date(2025, 1, 15)));
+        assert_eq!(parse("tod"), Some(date(2025, 1, 15)));
+        assert_eq!(parse("tomorrow"), Some(date(2025, 1, 16)));
+        assert_eq!(parse("tmrw"), Some(date(2025, 1, 16)));
+        assert_eq!(parse("yesterday"), Some(date(2025, 1, 14)));
+        assert_eq!(parse("  Tomorrow "), Some(date(2025, 1, 16)));
+    }
+
+    #[test]
+    fn bare_weekday_is_next_occurrence() {
+        assert_eq!(parse("thu"), Some(date(2025, 1, 16)));
+        assert_eq!(parse("friday"), Some(date(2025, 1, 17)));
+        assert_eq!(parse("mon"), Some(date(2025, 1, 20)));
+        // Same weekday as today means a week from now
+        assert_eq!(parse("wed"), Some(date(2025, 1, 22)));
+    }
+
+    #[test]
+    fn this_and_next_weekday() {
+        assert_eq!(parse("this fri"), Some(date(2025, 1, 17)));
+        assert_eq!(parse("this mon"), Some(date(2025, 1, 13)));
+        assert_eq!(parse("next tue"), Some(date(2025, 1, 21)));
+        assert_eq!(parse("next sun"), Some(date(2025, 1, 26)));
+        assert_eq!(parse_sunday("this sun"), Some(date(2025, 1, 12)));
+        assert_eq!(parse_sunday("next sun"), Some(date(2025, 1, 19)));
+        assert_eq!(parse_sunday("next sat"), Some(date(2025, 1, 25)));
+    }
+
+    #[test]
+    fn offsets() {
+        assert_eq!(parse("in 3 days"), Some(date(2025, 1, 18)));
+        assert_eq!(parse("in 1 day"), Some(date(2025, 1, 16)));
+        assert_eq!(parse("in 2 weeks"), Some(date(2025, 1, 29)));
+        assert_eq!(parse("in a week"), Some(date(2025, 1, 22)));
+        assert_eq!(parse("in a month"), Some(date(2025, 2, 15)));
+        assert_eq!(parse("3d"), Some(date(2025, 1, 18)));
+        assert_eq!(parse("in 2w"), Some(date(2025, 1, 29)));
+        assert_eq!(parse("in 3 fortnights"), None);
+    }
+
+    #[test]
+    fn month_offsets_clamp_to_month_end() {
+        let jan_31 = date(2025, 1, 31);
+        assert_eq!(
+            parse_date_expression("in 1 month", jan_31, WeekStart::Monday),
+            Some(date(2025, 2, 28))
+        );
+    }
+
+    #[test]
+    fn end_of_periods() {
+        assert_eq!(parse("eow"), Some(date(2025, 1, 19)));
+        assert_eq!(parse("end of week"), Some(date(2025, 1, 19)));
+        assert_eq!(parse_sunday("eow"), Some(date(2025, 1, 18)));
+        assert_eq!(parse("eom"), Some(date(2025, 1, 31)));
+        assert_eq!(parse("end of the month"), Some(date(2025, 1, 31)));
+        assert_eq!(parse("eoy"), Some(date(2025, 12, 31)));
+        assert_eq!(
+            parse_date_expression("eom", date(2024, 2, 10), WeekStart::Monday),
+            Some(date(2024, 2, 29))
+        );
+        assert_eq!(
+            parse_date_expression("eom", date(2025, 12, 3), WeekStart::Monday),
+            Some(date(2025, 12, 31))
+        );
+    }
+
+    #[test]
+    fn eow_on_last_day_of_week_is_today() {
+        let sunday = date(2025, 1, 19);
+        assert_eq!(parse_date_expression("eow", sunday, WeekStart::Monday), Some(sunday));
+    }
+
+    #[test]
+    fn next_week_and_month() {
+        assert_eq!(parse("next week"), Some(date(2025, 1, 20)));
+        assert_eq!(parse_sunday("next week"), Some(date(2025, 1, 19)));
+        assert_eq!(parse("next month"), Some(date(2025, 2, 1)));
+        assert_eq!(
+            parse_date_expression("next month", date(2025, 12, 31), WeekStart::Monday),
+            Some(date(2026, 1, 1))
+        );
+    }
+
+    #[test]
+    fn saturday_week_start() {
+        assert_eq!(
+            parse_date_expression("eow", today(), WeekStart::Saturday),
+            Some(date(2025, 1, 17))
+        );
+        assert_eq!(
+            parse_date_expression("next week", today(), WeekStart::Saturday),
+            Some(date(2025, 1, 18))
+        );
+    }
+
+    #[test]
+    fn absolute_dates() {
+        assert_eq!(parse("2025-03-04"), Some(date(2025, 3, 4)));
+        assert_eq!(parse("jan 31"), Some(date(2025, 1, 31)));
+        assert_eq!(parse("31 january"), Some(date(2025, 1, 31)));
+        assert_eq!(parse("feb 3rd"), Some(date(2025, 2, 3)));
+        // Already passed this year
+        assert_eq!(parse("jan 2"), Some(date(2026, 1, 2)));
+        assert_eq!(parse("feb 30"), None);
+    }
+
+    #[test]
+    fn unrecognized() {
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("someday"), None);
+        assert_eq!(parse("next blursday"), None);
+        assert_eq!(parse("in days"), None);
+    }
+
+    #[test]
+    fn week_start_from_locale() {
+        assert_eq!(WeekStart::from_locale("en_US.UTF-8"), WeekStart::Sunday);
+        assert_eq!(WeekStart::from_locale("en_GB.UTF-8"), WeekStart::Monday);
+        assert_eq!(WeekStart::from_locale("de-DE"), WeekStart::Monday);
+        assert_eq!(WeekStart::from_locale("ar_SA"), WeekStart::Saturday);
+        assert_eq!(WeekStart::from_locale("C"), WeekStart::Monday);
+    }
+}
@@ -0,0 +1,218 @@
+use crate::backup::{self, BackupEntry};
+use crate::shortcuts::ShortcutStatus;
+use crate::storage;
+use chrono::{DateTime, Duration, Local, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use tauri::plugin::PermissionState;
+use tauri::AppHandle;
+use tauri_plugin_notification::NotificationExt;
+
+// ============ Diagnostics ============
+//
+// Each check reports ok/warning/error with a human-readable detail, so the
+// health panel can show why PRs or backups aren't working. The gh checks
+// live in commands next to the gh path resolution; the rest are here.
+
+/// Warn once less than this fraction of a rate limit is left
+const RATE_LIMIT_WARN_FRACTION: f64 = 0.1;
+/// Warn once pending changes have missed this many backup intervals
+const BACKUP_STALE_INTERVALS: i64 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Ok,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticCheck {
+    /// Stable identifier, e.g. "gh_auth" or "images_dir"
+    pub id: String,
+    pub label: String,
+    pub status: CheckStatus,
+    pub detail: String,
+}
+
+impl DiagnosticCheck {
+    pub fn new(id: &str, label: &str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            id: id.to_string(),
+            label: label.to_string(),
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticsReport {
+    /// The worst status of any check
+    pub status: CheckStatus,
+    pub checks: Vec<DiagnosticCheck>,
+    pub ran_at: DateTime<Utc>,
+}
+
+pub fn report(checks: Vec<DiagnosticCheck>) -> DiagnosticsReport {
+    DiagnosticsReport {
+        status: checks.iter().map(|c| c.status).max().unwrap_or(CheckStatus::Ok),
+        checks,
+        ran_at: Utc::now(),
+    }
+}
+
+// ============ Storage Checks ============
+
+/// Whether `dir` exists and a file can be created in it
+fn check_dir(id: &str, label: &str, dir: &Path) -> DiagnosticCheck {
+    if !dir.is_dir() {
+        return DiagnosticCheck::new(id, label, CheckStatus::Error, format!("{} does not exist", dir.display()));
+    }
+
+    let probe = dir.join(".atulify-write-test");
+    match fs::write(&probe, b"ok") {
+        Ok(()) => {
+            let _ = fs::remove_file(&probe);
+            DiagnosticCheck::new(id, label, CheckStatus::Ok, format!("{} is writable", dir.display()))
+        }
+        Err(e) => DiagnosticCheck::new(
+            id,
+            label,
+            CheckStatus::Error,
+            format!("Can't write to {}: {}", dir.display(), e),
+        ),
+    }
+}
+
+fn check_backup_freshness(newest: Option<&BackupEntry>, now: NaiveDateTime, interval_minutes: u32) -> DiagnosticCheck {
+    let (id, label) = ("backup_freshness", "Latest Backup");
+    let Some(newest) = newest else {
+        return DiagnosticCheck::new(id, label, CheckStatus::Error, "No backups found");
+    };
+
+    let age = now - newest.created_at;
+    let detail = match age.num_hours() {
+        0 => format!("{} ({} minutes ago)", newest.name, age.num_minutes().max(0)),
+        h if h < 48 => format!("{} ({} hours ago)", newest.name, h),
+        _ => format!("{} ({} days ago)", newest.name, age.num_days()),
+    };
+    // Unchanged data isn't backed up again, so an old backup is only stale
+    // if there are changes it's missing
+    let missed = age > Duration::minutes(interval_minutes.max(1) as i64 * BACKUP_STALE_INTERVALS);
+    let status = if missed && backup::is_due(Some(newest), backup::data_modified(), now, interval_minutes) {
+        CheckStatus::Warning
+    } else {
+        CheckStatus::Ok
+    };
+    DiagnosticCheck::new(id, label, status, detail)
+}
+
+/// Data, backup and image directories, plus how recent the last backup is
+pub fn storage_checks() -> Vec<DiagnosticCheck> {
+    let mut checks = vec![
+        check_dir("data_dir", "Data Directory", &storage::get_app_dir()),
+        check_dir("backups_dir", "Backups Directory", &storage::get_backups_dir()),
+        check_dir("images_dir", "Images Directory", &storage::get_images_dir()),
+    ];
+
+    checks.push(check_backup_freshness(
+        backup::list().first(),
+        Local::now().naive_local(),
+        backup::policy().interval_minutes,
+    ));
+    checks
+}
+
+// ============ System Checks ============
+
+pub fn check_notifications(app: &AppHandle) -> DiagnosticCheck {
+    let (id, label) = ("notifications", "Notifications");
+    match app.notification().permission_state() {
+        Ok(PermissionState::Granted) => DiagnosticCheck::new(id, label, CheckStatus::Ok, "Allowed"),
+        Ok(PermissionState::Denied) => DiagnosticCheck::new(
+            id,
+            label,
+            CheckStatus::Warning,
+            "Blocked; allow them in System Settings → Notifications",
+        ),
+        Ok(_) => DiagnosticCheck::new(id, label, CheckStatus::Warning, "Not yet granted"),
+        Err(e) => DiagnosticCheck::new(id, label, CheckStatus::Warning, format!("Couldn't check permission: {}", e)),
+    }
+}
+
+pub fn check_shortcuts(statuses: &[ShortcutStatus]) -> DiagnosticCheck {
+    let (id, label) = ("shortcuts", "Global Shortcuts");
+    let failed: Vec<String> = statuses
+        .iter()
+        .filter_map(|s| {
+            let error = s.error.as_ref()?;
+            Some(format!("{} ({}): {}", s.action.label(), s.accelerator, error))
+        })
+        .collect();
+
+    if failed.is_empty() {
+        let registered = statuses.iter().filter(|s| s.registered).count();
+        DiagnosticCheck::new(id, label, CheckStatus::Ok, format!("{} registered", registered))
+    } else {
+        DiagnosticCheck::new(id, label, CheckStatus::Warning, failed.join("; "))
+    }
+}
+
+// ============ GitHub Rate Limits ============
+
+#[derive(Debug, Deserialize)]
+struct RateLimitResource {
+    limit: u64,
+    remaining: u64,
+    /// Unix timestamp the window resets at
+    reset: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct RateLimitResponse {
+    resources: HashMap<String, RateLimitResource>,
+}
+
+/// Checks for the REST, GraphQL and search limits from `gh api rate_limit`
+pub fn rate_limit_checks(json: &str) -> Vec<DiagnosticCheck> {
+    let response: RateLimitResponse = match serde_json::from_str(json) {
+        Ok(response) => response,
+        Err(e) => {
+            return vec![DiagnosticCheck::new(
+                "rate_limit",
+                "GitHub Rate Limits",
+                CheckStatus::Warning,
+                format!("Couldn't parse rate limits: {}", e),
+            )]
+        }
+    };
+
+    [("core", "REST API"), ("graphql", "GraphQL API"), ("search", "Search API")]
+        .iter()
+        .filter_map(|(key, name)| {
+            let resource = response.resources.get(*key)?;
+            let resets = Utc
+                .timestamp_opt(resource.reset, 0)
+                .single()
+                .map(|t| t.with_timezone(&Local).format("%H:%M").to_string())
+                .unwrap_or_else(|| "unknown".to_string());
+            let status = if resource.remaining == 0 {
+                CheckStatus::Error
+            } else if (resource.remaining as f64) < resource.limit as f64 * RATE_LIMIT_WARN_FRACTION {
+                CheckStatus::Warning
+            } else {
+                CheckStatus::Ok
+            };
+            Some(DiagnosticCheck::new(
+                &format!("rate_limit_{}", key),
+                &format!("{} Rate Limit", name),
+                status,
+                format!("{} of {} left, resets at {}", resource.remaining, resource.limit, resets),
+            ))
+        })
+        .collect()
+}
@@ -0,0 +1,131 @@
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use std::fmt;
+
+// ============ App Errors ============
+//
+// Commands return `AppError` so the UI can tell failures apart by `code`
+// rather than by message text. It serializes as `{ "code", "message" }`.
+// Helpers that still return `Result<_, String>` convert into `Internal`
+// with `?`, and `AppError` converts back into `String` for them too.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppError {
+    GhNotInstalled,
+    GhNotAuthenticated,
+    RateLimited,
+    NetworkUnavailable,
+    /// Any other gh failure, with its stderr
+    GhFailed(String),
+    /// data.json couldn't be parsed and no backup could be restored
+    DataCorrupt(String),
+    /// A backup failed verification against its manifest, or doesn't parse
+    BackupInvalid(String),
+    /// Reading or writing files under the app directory failed
+    Storage(String),
+    NotFound(String),
+    InvalidInput(String),
+    Internal(String),
+}
+
+impl AppError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::GhNotInstalled => "gh_not_installed",
+            Self::GhNotAuthenticated => "gh_not_authenticated",
+            Self::RateLimited => "rate_limited",
+            Self::NetworkUnavailable => "network_unavailable",
+            Self::GhFailed(_) => "gh_failed",
+            Self::DataCorrupt(_) => "data_corrupt",
+            Self::BackupInvalid(_) => "backup_invalid",
+            Self::Storage(_) => "storage",
+            Self::NotFound(_) => "not_found",
+            Self::InvalidInput(_) => "invalid_input",
+            Self::Internal(_) => "internal",
+        }
+    }
+
+    /// Classify a failed gh invocation from its stderr. `context` describes
+    /// what was being done, e.g. "Failed to search PRs".
+    pub fn from_gh_stderr(context: &str, stderr: &str) -> Self {
+        let lower = stderr.to_lowercase();
+        let has = |patterns: &[&str]| patterns.iter().any(|p| lower.contains(p));
+
+        if has(&["gh auth login", "not logged in", "authentication required", "bad credentials", "http 401"]) {
+            Self::GhNotAuthenticated
+        } else if has(&["rate limit", "abuse detection"]) {
+            Self::RateLimited
+        } else if has(&[
+            "could not resolve host",
+            "no such host",
+            "connection refused",
+            "connection reset",
+            "network is unreachable",
+            "i/o timeout",
+            "tls handshake timeout",
+            "dial tcp",
+        ]) {
+            Self::NetworkUnavailable
+        } else {
+            Self::GhFailed(format!("{}: {}", context, stderr.trim()))
+        }
+    }
+
+    /// Map an error spawning gh; a missing binary is reported as not installed
+    pub fn from_gh_spawn(err: std::io::Error) -> Self {
+        match err.kind() {
+            std::io::ErrorKind::NotFound => Self::GhNotInstalled,
+            _ => Self::Internal(format!("Failed to run gh command: {}", err)),
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GhNotInstalled => {
+                write!(f, "GitHub CLI (gh) not found. Please install it: https://cli.github.com/")
+            }
+            Self::GhNotAuthenticated => write!(f, "GitHub CLI isn't logged in. Run `gh auth login` and try again."),
+            Self::RateLimited => write!(f, "GitHub rate limit reached. Try again in a few minutes."),
+            Self::NetworkUnavailable => write!(f, "Couldn't reach GitHub. Check your network connection."),
+            Self::DataCorrupt(detail) => write!(f, "Data file is corrupt and no backup could be restored: {}", detail),
+            Self::BackupInvalid(detail) => write!(f, "Backup failed verification: {}", detail),
+            Self::GhFailed(msg)
+            | Self::Storage(msg)
+            | Self::NotFound(msg)
+            | Self::InvalidInput(msg)
+            | Self::Internal(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("AppError", 2)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
+
+impl From<String> for AppError {
+    fn from(message: String) -> Self {
+        Self::Internal(message)
+    }
+}
+
+impl From<&str> for AppError {
+    fn from(message: &str) -> Self {
+        Self::Internal(message.to_string())
+    }
+}
+
+impl From<AppError> for String {
+    fn from(err: AppError) -> Self {
+        err.to_string()
+    }
+}
+
+pub type AppResult<T> = Result<T, AppError>;
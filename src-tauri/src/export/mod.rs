@@ -0,0 +1,590 @@
+use crate::models::{BragDoc, BragEntry, Tag};
+use base64::Engine;
+use chrono::{Local, NaiveDate};
+use printpdf::{
+    image_crate, BuiltinFont, Image, ImageTransform, IndirectFontRef, Mm, PdfDocument, PdfDocumentReference,
+    PdfLayerReference,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+// ============ Brag Doc Export ============
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    Markdown,
+    Html,
+    Pdf,
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Html => "html",
+            Self::Pdf => "pdf",
+        }
+    }
+
+    pub fn filter_name(self) -> &'static str {
+        match self {
+            Self::Markdown => "Markdown",
+            Self::Html => "HTML",
+            Self::Pdf => "PDF",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportGrouping {
+    #[default]
+    Month,
+    Tag,
+}
+
+/// An export's contents, plus anything that couldn't be included in it
+pub struct Rendered {
+    pub contents: Vec<u8>,
+    pub warnings: Vec<String>,
+}
+
+/// Where an export was written, and what it had to leave out
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedFile {
+    pub path: String,
+    pub warnings: Vec<String>,
+}
+
+/// A heading and the entries listed under it
+struct Section<'a> {
+    heading: String,
+    entries: Vec<&'a BragEntry>,
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%B %-d, %Y").to_string()
+}
+
+/// Group entries into sections, each sorted by date. With tag grouping an
+/// entry appears under every tag it has, and untagged entries come last.
+fn group_entries<'a>(doc: &'a BragDoc, tags: &[Tag], grouping: ExportGrouping) -> Vec<Section<'a>> {
+    let mut entries: Vec<&BragEntry> = doc.entries.iter().collect();
+    entries.sort_by_key(|e| e.date);
+
+    let mut sections: Vec<Section> = Vec::new();
+    match grouping {
+        ExportGrouping::Month => {
+            for entry in entries {
+                let heading = entry.date.format("%B %Y").to_string();
+                match sections.last_mut() {
+                    Some(section) if section.heading == heading => section.entries.push(entry),
+                    _ => sections.push(Section {
+                        heading,
+                        entries: vec![entry],
+                    }),
+                }
+            }
+        }
+        ExportGrouping::Tag => {
+            for tag in tags {
+                let tagged: Vec<&BragEntry> = entries.iter().copied().filter(|e| e.tag_ids.contains(&tag.id)).collect();
+                if !tagged.is_empty() {
+                    sections.push(Section {
+                        heading: tag.name.clone(),
+                        entries: tagged,
+                    });
+                }
+            }
+
+            // Entries with no tags, or only tags that have since been deleted
+            let untagged: Vec<&BragEntry> = entries
+                .iter()
+                .copied()
+                .filter(|e| !e.tag_ids.iter().any(|id| tags.iter().any(|t| &t.id == id)))
+                .collect();
+            if !untagged.is_empty() {
+                sections.push(Section {
+                    heading: "Untagged".to_string(),
+                    entries: untagged,
+                });
+            }
+        }
+    }
+    sections
+}
+
+/// Image paths are stored as absolute paths by `save_image`, but fall back to
+/// looking the file name up in the images directory
+fn resolve_image_path(image: &str, images_dir: &Path) -> Option<PathBuf> {
+    let path = PathBuf::from(image);
+    if path.is_absolute() && path.exists() {
+        return Some(path);
+    }
+    let in_images_dir = images_dir.join(path.file_name()?);
+    in_images_dir.exists().then_some(in_images_dir)
+}
+
+fn image_mime_type(path: &Path) -> &'static str {
+    match path.extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase()).as_deref() {
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("svg") => "image/svg+xml",
+        _ => "image/png",
+    }
+}
+
+// ============ Markdown ============
+
+pub fn to_markdown(doc: &BragDoc, tags: &[Tag], grouping: ExportGrouping) -> String {
+    let mut lines: Vec<String> = Vec::new();
+
+    lines.push(format!("# {}", doc.title));
+    lines.push(String::new());
+    lines.push(format!(
+        "**Period:** {} - {}",
+        format_date(doc.start_date),
+        format_date(doc.end_date)
+    ));
+    lines.push(String::new());
+    lines.push("---".to_string());
+    lines.push(String::new());
+
+    let sections = group_entries(doc, tags, grouping);
+    if sections.is_empty() {
+        lines.push("*No accomplishments recorded yet.*".to_string());
+    }
+
+    for section in sections {
+        lines.push(format!("## {}", section.heading));
+        lines.push(String::new());
+
+        for entry in section.entries {
+            lines.push(format!("### {}", entry.title));
+            lines.push(String::new());
+            lines.push(format!("*{}*", format_date(entry.date)));
+            lines.push(String::new());
+
+            if !entry.description.is_empty() {
+                lines.push(entry.description.clone());
+                lines.push(String::new());
+            }
+
+            if !entry.links.is_empty() {
+                lines.push("**Links:**".to_string());
+                for link in &entry.links {
+                    lines.push(format!("- {}", link));
+                }
+                lines.push(String::new());
+            }
+
+            for image in &entry.images {
+                lines.push(format!("![]({})", image.replace(' ', "%20")));
+            }
+            if !entry.images.is_empty() {
+                lines.push(String::new());
+            }
+        }
+
+        lines.push("---".to_string());
+        lines.push(String::new());
+    }
+
+    lines.push(String::new());
+    lines.push(format!("*Exported from Atulify on {}*", Local::now().format("%Y-%m-%d")));
+
+    lines.join("\n")
+}
+
+// ============ HTML ============
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const HTML_STYLE: &str = "body{font-family:-apple-system,BlinkMacSystemFont,'Segoe UI',Roboto,sans-serif;\
+max-width:760px;margin:40px auto;padding:0 20px;color:#1a1a1a;line-height:1.5}\
+h1{margin-bottom:4px}h2{border-bottom:1px solid #ddd;padding-bottom:4px;margin-top:32px}\
+h3{margin-bottom:0}.date{color:#666;font-style:italic;margin-top:2px}\
+img{max-width:100%;border-radius:6px;margin:8px 0}footer{color:#888;margin-top:40px;font-size:13px}";
+
+/// Render a standalone HTML page with images embedded as data URIs
+pub fn to_html(doc: &BragDoc, tags: &[Tag], grouping: ExportGrouping, images_dir: &Path) -> String {
+    let mut html = String::new();
+
+    html.push_str("<!doctype html>\n<html lang=\"en\">\n<head>\n<meta charset=\"UTF-8\">\n");
+    html.push_str(&format!("<title>{}</title>\n", escape_html(&doc.title)));
+    html.push_str(&format!("<style>{}</style>\n</head>\n<body>\n", HTML_STYLE));
+    html.push_str(&format!("<h1>{}</h1>\n", escape_html(&doc.title)));
+    html.push_str(&format!(
+        "<p><strong>Period:</strong> {} - {}</p>\n",
+        format_date(doc.start_date),
+        format_date(doc.end_date)
+    ));
+
+    let sections = group_entries(doc, tags, grouping);
+    if sections.is_empty() {
+        html.push_str("<p><em>No accomplishments recorded yet.</em></p>\n");
+    }
+
+    for section in sections {
+        html.push_str(&format!("<h2>{}</h2>\n", escape_html(&section.heading)));
+
+        for entry in section.entries {
+            html.push_str(&format!("<h3>{}</h3>\n", escape_html(&entry.title)));
+            html.push_str(&format!("<p class=\"date\">{}</p>\n", format_date(entry.date)));
+
+            if !entry.description.is_empty() {
+                html.push_str(&format!(
+                    "<p>{}</p>\n",
+                    escape_html(&entry.description).replace('\n', "<br>\n")
+                ));
+            }
+
+            if !entry.links.is_empty() {
+                html.push_str("<ul>\n");
+                for link in &entry.links {
+                    let link = escape_html(link);
+                    html.push_str(&format!("<li><a href=\"{}\">{}</a></li>\n", link, link));
+                }
+                html.push_str("</ul>\n");
+            }
+
+            for image in &entry.images {
+                let Some(path) = resolve_image_path(image, images_dir) else {
+                    continue;
+                };
+                let Ok(bytes) = std::fs::read(&path) else {
+                    continue;
+                };
+                html.push_str(&format!(
+                    "<img src=\"data:{};base64,{}\" alt=\"\">\n",
+                    image_mime_type(&path),
+                    base64::engine::general_purpose::STANDARD.encode(bytes)
+                ));
+            }
+        }
+    }
+
+    html.push_str(&format!(
+        "<footer>Exported from Atulify on {}</footer>\n</body>\n</html>\n",
+        Local::now().format("%Y-%m-%d")
+    ));
+    html
+}
+
+// ============ PDF ============
+
+const PAGE_WIDTH_MM: f32 = 210.0;
+const PAGE_HEIGHT_MM: f32 = 297.0;
+const MARGIN_MM: f32 = 20.0;
+const PT_TO_MM: f32 = 0.3528;
+const MAX_IMAGE_HEIGHT_MM: f32 = 90.0;
+
+#[derive(Clone, Copy)]
+enum FontStyle {
+    Regular,
+    Bold,
+    Italic,
+}
+
+/// Minimal flowing-text layout on top of printpdf's builtin Helvetica fonts
+struct PdfWriter {
+    doc: PdfDocumentReference,
+    layer: PdfLayerReference,
+    regular: IndirectFontRef,
+    bold: IndirectFontRef,
+    italic: IndirectFontRef,
+    /// Baseline of the next line, measured from the bottom of the page
+    y: f32,
+    /// Characters the builtin fonts can't draw, replaced with '?'
+    unsupported: BTreeSet<char>,
+    /// Images left out, with the reason
+    skipped_images: Vec<String>,
+}
+
+impl PdfWriter {
+    fn new(title: &str) -> Result<Self, String> {
+        let (doc, page, layer) = PdfDocument::new(title, Mm(PAGE_WIDTH_MM), Mm(PAGE_HEIGHT_MM), "Layer 1");
+        let font = |f| doc.add_builtin_font(f).map_err(|e| format!("Failed to load PDF font: {}", e));
+        let regular = font(BuiltinFont::Helvetica)?;
+        let bold = font(BuiltinFont::HelveticaBold)?;
+        let italic = font(BuiltinFont::HelveticaOblique)?;
+        let layer = doc.get_page(page).get_layer(layer);
+
+        Ok(Self {
+            doc,
+            layer,
+            regular,
+            bold,
+            italic,
+            y: PAGE_HEIGHT_MM - MARGIN_MM,
+            unsupported: BTreeSet::new(),
+            skipped_images: Vec::new(),
+        })
+    }
+
+    fn new_page(&mut self) {
+        let (page, layer) = self.doc.add_page(Mm(PAGE_WIDTH_MM), Mm(PAGE_HEIGHT_MM), "Layer 1");
+        self.layer = self.doc.get_page(page).get_layer(layer);
+        self.y = PAGE_HEIGHT_MM - MARGIN_MM;
+    }
+
+    fn ensure_space(&mut self, height: f32) {
+        if self.y - height < MARGIN_MM {
+            self.new_page();
+        }
+    }
+
+    fn gap(&mut self, height: f32) {
+        self.y -= height;
+    }
+
+    /// Write word-wrapped text. Builtin fonts have no metrics available, so
+    /// wrapping uses Helvetica's average glyph width.
+    fn text(&mut self, text: &str, size: f32, style: FontStyle) {
+        let line_height = size * PT_TO_MM * 1.4;
+        let max_chars = ((PAGE_WIDTH_MM - 2.0 * MARGIN_MM) / (size * PT_TO_MM * 0.5)) as usize;
+        let font = match style {
+            FontStyle::Regular => self.regular.clone(),
+            FontStyle::Bold => self.bold.clone(),
+            FontStyle::Italic => self.italic.clone(),
+        };
+
+        let text = to_win_ansi(text, &mut self.unsupported);
+        for paragraph in text.lines() {
+            for line in wrap_line(paragraph, max_chars) {
+                self.ensure_space(line_height);
+                self.y -= line_height;
+                self.layer.use_text(line, size, Mm(MARGIN_MM), Mm(self.y), &font);
+            }
+        }
+    }
+
+    /// Note an image that couldn't be drawn, in the PDF and in the warnings
+    fn skip_image(&mut self, image: &str, reason: &str) {
+        let name = Path::new(image).file_name().map_or(image.into(), |n| n.to_string_lossy());
+        self.text(&format!("[Image not included: {}]", name), 10.0, FontStyle::Italic);
+        self.skipped_images.push(format!("{} ({})", name, reason));
+    }
+
+    /// Draw an image scaled to fit the text width and a maximum height
+    fn image(&mut self, path: &Path) {
+        let decoded = match image_crate::open(path) {
+            Ok(decoded) => decoded,
+            Err(e) => return self.skip_image(&path.to_string_lossy(), &e.to_string()),
+        };
+        let (width_px, height_px) = (decoded.width() as f32, decoded.height() as f32);
+        if width_px == 0.0 || height_px == 0.0 {
+            return self.skip_image(&path.to_string_lossy(), "empty image");
+        }
+
+        // Pick the DPI that makes the image fit, never enlarging past 96 DPI
+        let max_width = PAGE_WIDTH_MM - 2.0 * MARGIN_MM;
+        let dpi = (width_px * 25.4 / max_width)
+            .max(height_px * 25.4 / MAX_IMAGE_HEIGHT_MM)
+            .max(96.0);
+        let height_mm = height_px * 25.4 / dpi;
+
+        self.ensure_space(height_mm);
+        self.y -= height_mm;
+        Image::from_dynamic_image(&decoded).add_to_layer(
+            self.layer.clone(),
+            ImageTransform {
+                translate_x: Some(Mm(MARGIN_MM)),
+                translate_y: Some(Mm(self.y)),
+                dpi: Some(dpi),
+                ..Default::default()
+            },
+        );
+        self.gap(3.0);
+    }
+
+    fn finish(self) -> Result<Rendered, String> {
+        let mut warnings = Vec::new();
+        if !self.unsupported.is_empty() {
+            let chars: String = self.unsupported.iter().take(20).collect();
+            warnings.push(format!(
+                "{} characters the PDF font can't show were replaced with '?': {}",
+                self.unsupported.len(),
+                chars
+            ));
+        }
+        warnings.extend(self.skipped_images.iter().map(|image| format!("Image not included: {}", image)));
+
+        let contents = self
+            .doc
+            .save_to_bytes()
+            .map_err(|e| format!("Failed to write PDF: {}", e))?;
+        Ok(Rendered { contents, warnings })
+    }
+}
+
+/// Characters in the builtin fonts' WinAnsi encoding beyond ASCII and Latin-1
+const WIN_ANSI_EXTRAS: &str = "€‚ƒ„…†‡ˆ‰Š‹ŒŽ‘’“”•–—˜™š›œžŸ";
+
+/// Close stand-ins for common characters WinAnsi lacks
+fn win_ansi_substitute(c: char) -> Option<&'static str> {
+    Some(match c {
+        '\t' => "    ",
+        '\u{2000}'..='\u{200A}' | '\u{202F}' | '\u{205F}' => " ",
+        '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{FE0F}' | '\u{FEFF}' => "",
+        '\u{2010}' | '\u{2011}' | '\u{2012}' | '\u{2212}' => "-",
+        '\u{2015}' => "\u{2014}",
+        '\u{2032}' => "'",
+        '\u{2033}' => "\"",
+        '\u{2190}' => "<-",
+        '\u{2192}' => "->",
+        '\u{2194}' => "<->",
+        '\u{21D2}' => "=>",
+        '\u{2260}' => "!=",
+        '\u{2264}' => "<=",
+        '\u{2265}' => ">=",
+        '\u{2248}' => "~",
+        '\u{2713}' | '\u{2714}' | '\u{2705}' => "[x]",
+        '\u{2717}' | '\u{2718}' | '\u{274C}' => "[ ]",
+        _ => return None,
+    })
+}
+
+/// Replace what the builtin fonts can't draw, which printpdf would
+/// otherwise drop silently, collecting anything without a stand-in
+fn to_win_ansi(text: &str, unsupported: &mut BTreeSet<char>) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\n' | ' '..='~' | '\u{A0}'..='\u{FF}') || WIN_ANSI_EXTRAS.contains(c) {
+            out.push(c);
+        } else if let Some(substitute) = win_ansi_substitute(c) {
+            out.push_str(substitute);
+        } else {
+            unsupported.insert(c);
+            out.push('?');
+        }
+    }
+    out
+}
+
+/// Word-wrap to `max_chars`, hard-breaking words that are longer than a
+/// whole line, like URLs
+fn wrap_line(text: &str, max_chars: usize) -> Vec<String> {
+    let max_chars = max_chars.max(1);
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+
+    for mut word in text.split_whitespace() {
+        while let Some((split, _)) = word.char_indices().nth(max_chars) {
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            lines.push(word[..split].to_string());
+            word = &word[split..];
+        }
+        if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > max_chars {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+
+    // Keep blank lines so paragraph spacing survives
+    lines.push(current);
+    lines
+}
+
+pub fn to_pdf(doc: &BragDoc, tags: &[Tag], grouping: ExportGrouping, images_dir: &Path) -> Result<Rendered, String> {
+    let mut pdf = PdfWriter::new(&doc.title)?;
+
+    pdf.text(&doc.title, 22.0, FontStyle::Bold);
+    pdf.text(
+        &format!("Period: {} - {}", format_date(doc.start_date), format_date(doc.end_date)),
+        11.0,
+        FontStyle::Regular,
+    );
+    pdf.gap(4.0);
+
+    let sections = group_entries(doc, tags, grouping);
+    if sections.is_empty() {
+        pdf.text("No accomplishments recorded yet.", 11.0, FontStyle::Italic);
+    }
+
+    for section in sections {
+        pdf.gap(6.0);
+        pdf.text(&section.heading, 16.0, FontStyle::Bold);
+        pdf.gap(2.0);
+
+        for entry in section.entries {
+            pdf.gap(3.0);
+            pdf.text(&entry.title, 13.0, FontStyle::Bold);
+            pdf.text(&format_date(entry.date), 10.0, FontStyle::Italic);
+
+            if !entry.description.is_empty() {
+                pdf.gap(1.0);
+                pdf.text(&entry.description, 11.0, FontStyle::Regular);
+            }
+
+            for link in &entry.links {
+                pdf.text(&format!("- {}", link), 10.0, FontStyle::Regular);
+            }
+
+            for image in &entry.images {
+                pdf.gap(2.0);
+                match resolve_image_path(image, images_dir) {
+                    Some(path) => pdf.image(&path),
+                    None => pdf.skip_image(image, "file not found"),
+                }
+            }
+        }
+    }
+
+    pdf.gap(8.0);
+    pdf.text(
+        &format!("Exported from Atulify on {}", Local::now().format("%Y-%m-%d")),
+        9.0,
+        FontStyle::Italic,
+    );
+    pdf.finish()
+}
+
+/// Render `doc` in the given format; only PDFs can lose anything
+pub fn export(
+    doc: &BragDoc,
+    tags: &[Tag],
+    format: ExportFormat,
+    grouping: ExportGrouping,
+    images_dir: &Path,
+) -> Result<Rendered, String> {
+    let text = |contents: String| Rendered {
+        contents: contents.into_bytes(),
+        warnings: Vec::new(),
+    };
+    match format {
+        ExportFormat::Markdown => Ok(text(to_markdown(doc, tags, grouping))),
+        ExportFormat::Html => Ok(text(to_html(doc, tags, grouping, images_dir))),
+        ExportFormat::Pdf => to_pdf(doc, tags, grouping, images_dir),
+    }
+}
+
+/// Default file name for an export, e.g. "h1-2025-brag-doc.pdf"
+pub fn default_file_name(doc: &BragDoc, format: ExportFormat) -> String {
+    let slug: String = doc
+        .title
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect::<String>()
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+
+    let slug = if slug.is_empty() { "brag-doc".to_string() } else { slug };
+    format!("{}.{}", slug, format.extension())
+}
@@ -0,0 +1,240 @@
+use crate::error::AppError;
+use crate::history;
+use crate::logging;
+use crate::models::{FocusSession, FocusSettings};
+use crate::shortcuts::notify;
+use crate::storage;
+use chrono::{DateTime, Duration, Utc};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tauri::{AppHandle, Emitter};
+use uuid::Uuid;
+
+// ============ Focus Sessions ============
+//
+// A focus session cycles work -> break -> work ... for one task until it is
+// stopped. A ticker thread updates the tray title every second and moves to
+// the next phase when the current one runs out. Each work phase is logged
+// to `AppData::focus_sessions` when it ends or the session is stopped.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FocusPhase {
+    Work,
+    ShortBreak,
+    LongBreak,
+}
+
+impl FocusPhase {
+    fn minutes(self, settings: &FocusSettings) -> u32 {
+        let minutes = match self {
+            Self::Work => settings.work_minutes,
+            Self::ShortBreak => settings.short_break_minutes,
+            Self::LongBreak => settings.long_break_minutes,
+        };
+        minutes.max(1)
+    }
+}
+
+/// Current session state, reported to the UI
+#[derive(Debug, Clone, Serialize)]
+pub struct FocusStatus {
+    pub task_id: String,
+    pub task_title: String,
+    pub phase: FocusPhase,
+    pub phase_started_at: DateTime<Utc>,
+    pub phase_ends_at: DateTime<Utc>,
+    pub remaining_seconds: i64,
+    /// Work phases finished in this session
+    pub completed_pomodoros: u32,
+}
+
+struct ActiveFocus {
+    generation: u64,
+    settings: FocusSettings,
+    status: FocusStatus,
+}
+
+impl ActiveFocus {
+    fn status(&self, now: DateTime<Utc>) -> FocusStatus {
+        FocusStatus {
+            remaining_seconds: (self.status.phase_ends_at - now).num_seconds().max(0),
+            ..self.status.clone()
+        }
+    }
+
+    /// Log entry for the current work phase, ending at `now`
+    fn work_session(&self, now: DateTime<Utc>, completed: bool) -> Option<FocusSession> {
+        (self.status.phase == FocusPhase::Work).then(|| FocusSession {
+            id: Uuid::new_v4().to_string(),
+            task_id: self.status.task_id.clone(),
+            started_at: self.status.phase_started_at,
+            ended_at: now.min(self.status.phase_ends_at),
+            planned_minutes: FocusPhase::Work.minutes(&self.settings),
+            completed,
+        })
+    }
+
+    /// Move to the next phase, returning the log entry for a finished work phase
+    fn advance(&mut self, now: DateTime<Utc>) -> Option<FocusSession> {
+        let session = self.work_session(now, true);
+
+        let next = match self.status.phase {
+            FocusPhase::Work => {
+                self.status.completed_pomodoros += 1;
+                let every = self.settings.sessions_before_long_break.max(1);
+                if self.status.completed_pomodoros.is_multiple_of(every) {
+                    FocusPhase::LongBreak
+                } else {
+                    FocusPhase::ShortBreak
+                }
+            }
+            FocusPhase::ShortBreak | FocusPhase::LongBreak => FocusPhase::Work,
+        };
+
+        self.status.phase = next;
+        self.status.phase_started_at = now;
+        self.status.phase_ends_at = now + Duration::minutes(next.minutes(&self.settings) as i64);
+        session
+    }
+}
+
+static ACTIVE: Lazy<Mutex<Option<ActiveFocus>>> = Lazy::new(|| Mutex::new(None));
+
+/// Bumped on every start so a stale ticker thread notices and exits
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+fn set_tray_title(app: &AppHandle, title: Option<String>) {
+    if let Some(tray) = app.tray_by_id(crate::TRAY_ID) {
+        let _ = tray.set_title(title);
+    }
+}
+
+fn tray_title(status: &FocusStatus) -> String {
+    let icon = if status.phase == FocusPhase::Work { "🍅" } else { "☕" };
+    format!(
+        "{} {:02}:{:02}",
+        icon,
+        status.remaining_seconds / 60,
+        status.remaining_seconds % 60
+    )
+}
+
+fn record_session(app: &AppHandle, session: FocusSession) {
+    let result = storage::load_data().and_then(|before| {
+        let mut data = before.clone();
+        data.focus_sessions.push(session);
+        storage::save_data(&data)?;
+        history::record(app, Some("Log focus session"), &before, &data);
+        Ok(())
+    });
+
+    match result {
+        Ok(()) => {
+            let _ = app.emit("data::changed", ());
+        }
+        Err(e) => logging::error("focus", &format!("Failed to record focus session: {}", e)),
+    }
+}
+
+fn notify_phase(app: &AppHandle, status: &FocusStatus) {
+    let minutes = (status.phase_ends_at - status.phase_started_at).num_minutes();
+    match status.phase {
+        FocusPhase::Work => notify(app, "Break's over", &format!("Back to: {}", status.task_title)),
+        FocusPhase::ShortBreak | FocusPhase::LongBreak => notify(
+            app,
+            "Focus session done",
+            &format!("Take a {}-minute break", minutes),
+        ),
+    }
+}
+
+fn run_ticker(app: AppHandle, generation: u64) {
+    loop {
+        std::thread::sleep(std::time::Duration::from_secs(1));
+        let now = Utc::now();
+
+        let (status, finished_session, phase_changed) = {
+            let mut active = ACTIVE.lock();
+            let Some(focus) = active.as_mut().filter(|f| f.generation == generation) else {
+                return;
+            };
+
+            // After sleep several phases may have elapsed; only move on one at a time
+            let phase_changed = now >= focus.status.phase_ends_at;
+            let finished_session = if phase_changed { focus.advance(now) } else { None };
+            (focus.status(now), finished_session, phase_changed)
+        };
+
+        set_tray_title(&app, Some(tray_title(&status)));
+        if phase_changed {
+            notify_phase(&app, &status);
+            let _ = app.emit("focus::changed", Some(&status));
+        }
+        if let Some(session) = finished_session {
+            record_session(&app, session);
+        }
+    }
+}
+
+/// Start a focus session on a task, replacing any session already running
+pub fn start(app: &AppHandle, task_id: &str) -> Result<FocusStatus, AppError> {
+    let data = storage::load_data()?;
+    let task = data
+        .tasks
+        .iter()
+        .find(|t| t.id == task_id && t.deleted_at.is_none())
+        .ok_or_else(|| AppError::NotFound(format!("Task '{}' not found", task_id)))?;
+    if task.completed || task.archived {
+        return Err(AppError::InvalidInput("Can't focus on a finished task".to_string()));
+    }
+
+    let now = Utc::now();
+    let settings = data.settings.focus.clone();
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let focus = ActiveFocus {
+        generation,
+        status: FocusStatus {
+            task_id: task.id.clone(),
+            task_title: task.title.clone(),
+            phase: FocusPhase::Work,
+            phase_started_at: now,
+            phase_ends_at: now + Duration::minutes(FocusPhase::Work.minutes(&settings) as i64),
+            remaining_seconds: 0,
+            completed_pomodoros: 0,
+        },
+        settings,
+    };
+    let status = focus.status(now);
+
+    let previous = ACTIVE.lock().replace(focus);
+    if let Some(session) = previous.and_then(|p| p.work_session(now, false)) {
+        record_session(app, session);
+    }
+
+    set_tray_title(app, Some(tray_title(&status)));
+    let _ = app.emit("focus::changed", Some(&status));
+
+    let ticker_app = app.clone();
+    std::thread::spawn(move || run_ticker(ticker_app, generation));
+    Ok(status)
+}
+
+/// Stop the running session, logging a partial work phase if one was under way
+pub fn stop(app: &AppHandle) -> Option<FocusSession> {
+    let focus = ACTIVE.lock().take()?;
+    let session = focus.work_session(Utc::now(), false);
+    if let Some(session) = &session {
+        record_session(app, session.clone());
+    }
+
+    set_tray_title(app, None);
+    let _ = app.emit("focus::changed", None::<FocusStatus>);
+    session
+}
+
+pub fn status() -> Option<FocusStatus> {
+    ACTIVE.lock().as_ref().map(|f| f.status(Utc::now()))
+}
@@ -0,0 +1,304 @@
+use crate::dates::{self, WeekStart};
+use crate::storage;
+use chrono::{DateTime, Datelike, Duration, Local, Months, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+// ============ GitHub Stats Cache ============
+//
+// Merged PRs I authored or reviewed are kept on disk, so ranges and series
+// are computed locally and only PRs merged since the last sync (or before
+// the earliest one fetched) need to come from GitHub.
+
+const CACHE_FILE: &str = "github-stats.json";
+const SYNC_TTL_SECS: i64 = 600; // 10 minutes
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsPr {
+    pub number: u64,
+    pub title: String,
+    pub url: String,
+    pub merged_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GitHubStatsCache {
+    /// PRs I authored
+    pub merged: Vec<StatsPr>,
+    /// PRs by others that I reviewed
+    pub reviewed: Vec<StatsPr>,
+    /// Everything merged on or after this date has been fetched
+    pub covered_from: Option<NaiveDate>,
+    pub synced_at: Option<DateTime<Utc>>,
+}
+
+fn cache_path() -> PathBuf {
+    storage::get_app_dir().join(CACHE_FILE)
+}
+
+/// Load the cache; a missing or unreadable file just means refetching
+pub fn load_cache() -> GitHubStatsCache {
+    fs::read_to_string(cache_path())
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_cache(cache: &GitHubStatsCache) -> Result<(), String> {
+    storage::ensure_directories()?;
+    let path = cache_path();
+    let contents = serde_json::to_string(cache).map_err(|e| e.to_string())?;
+    let temp_path = path.with_extension("json.tmp");
+    fs::write(&temp_path, contents).map_err(|e| format!("Failed to write stats cache: {}", e))?;
+    fs::rename(&temp_path, &path).map_err(|e| format!("Failed to write stats cache: {}", e))
+}
+
+/// Merge date to fetch from so the cache covers `start` through today, or
+/// None if it already covers that range and was synced recently (unless `force`)
+pub fn sync_from(cache: &GitHubStatsCache, start: NaiveDate, now: DateTime<Utc>, force: bool) -> Option<NaiveDate> {
+    match (cache.covered_from, cache.synced_at) {
+        (Some(covered), Some(synced)) if covered <= start => {
+            // Overlap a day so PRs merged around the last sync aren't missed
+            let stale = force || (now - synced).num_seconds() >= SYNC_TTL_SECS;
+            stale.then(|| synced.with_timezone(&Local).date_naive() - Duration::days(1))
+        }
+        // Older history is missing; refetch the whole range in one go
+        _ => Some(start),
+    }
+}
+
+fn upsert(existing: &mut Vec<StatsPr>, fetched: Vec<StatsPr>) {
+    for pr in fetched {
+        match existing.iter_mut().find(|p| p.number == pr.number) {
+            Some(slot) => *slot = pr,
+            None => existing.push(pr),
+        }
+    }
+    existing.sort_by_key(|p| p.merged_at);
+}
+
+/// Record PRs fetched for merges on or after `from`
+pub fn merge(
+    cache: &mut GitHubStatsCache,
+    from: NaiveDate,
+    merged: Vec<StatsPr>,
+    reviewed: Vec<StatsPr>,
+    now: DateTime<Utc>,
+) {
+    upsert(&mut cache.merged, merged);
+    upsert(&mut cache.reviewed, reviewed);
+    cache.covered_from = Some(cache.covered_from.map_or(from, |c| c.min(from)));
+    cache.synced_at = Some(now);
+}
+
+// ============ Ranges and Series ============
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Granularity {
+    Week,
+    Month,
+}
+
+/// Counts for one week or month, clipped to the requested range
+#[derive(Debug, Clone, Serialize)]
+pub struct PeriodCount {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub merged: u32,
+    pub reviewed: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GitHubRangeStats {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub merged: u32,
+    pub reviewed: u32,
+    pub granularity: Granularity,
+    pub series: Vec<PeriodCount>,
+    pub synced_at: Option<DateTime<Utc>>,
+}
+
+/// PRs merged between local dates `start` and `end` (inclusive)
+pub fn count(prs: &[StatsPr], start: NaiveDate, end: NaiveDate) -> u32 {
+    prs.iter()
+        .map(|p| p.merged_at.with_timezone(&Local).date_naive())
+        .filter(|d| *d >= start && *d <= end)
+        .count() as u32
+}
+
+fn period_start(date: NaiveDate, granularity: Granularity, week_start: WeekStart) -> NaiveDate {
+    match granularity {
+        Granularity::Week => dates::start_of_week(date, week_start),
+        Granularity::Month => date.with_day(1).unwrap_or(date),
+    }
+}
+
+fn next_period(start: NaiveDate, granularity: Granularity) -> NaiveDate {
+    match granularity {
+        Granularity::Week => start + Duration::weeks(1),
+        Granularity::Month => start + Months::new(1),
+    }
+}
+
+pub fn range_stats(
+    cache: &GitHubStatsCache,
+    start: NaiveDate,
+    end: NaiveDate,
+    granularity: Granularity,
+    week_start: WeekStart,
+) -> GitHubRangeStats {
+    let mut series = Vec::new();
+    let mut period = period_start(start, granularity, week_start);
+    while period <= end {
+        let next = next_period(period, granularity);
+        let (from, to) = (period.max(start), (next - Duration::days(1)).min(end));
+        series.push(PeriodCount {
+            start: from,
+            end: to,
+            merged: count(&cache.merged, from, to),
+            reviewed: count(&cache.reviewed, from, to),
+        });
+        period = next;
+    }
+
+    GitHubRangeStats {
+        start,
+        end,
+        merged: count(&cache.merged, start, end),
+        reviewed: count(&cache.reviewed, start, end),
+        granularity,
+        series,
+        synced_at: cache.synced_at,
+    }
+}
+
+// ============ Review Turnaround ============
+
+/// A PR I was asked to review, with when I was requested (directly or via
+/// my team) and when I submitted reviews
+#[derive(Debug, Clone)]
+pub struct RequestedPr {
+    pub number: u64,
+    pub title: String,
+    pub url: String,
+    /// OPEN, CLOSED or MERGED
+    pub state: String,
+    pub requested_at: Vec<DateTime<Utc>>,
+    pub reviewed_at: Vec<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WeekTurnaround {
+    pub week_start: NaiveDate,
+    pub reviews: u32,
+    pub median_seconds: Option<i64>,
+    pub p90_seconds: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UnreviewedPr {
+    pub number: u64,
+    pub title: String,
+    pub url: String,
+    pub state: String,
+    pub requested_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReviewTurnaround {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub reviews: u32,
+    pub median_seconds: Option<i64>,
+    pub p90_seconds: Option<i64>,
+    /// Bucketed by the week I was first requested
+    pub weeks: Vec<WeekTurnaround>,
+    /// Requested in range with no review from me since; oldest request first
+    pub never_reviewed: Vec<UnreviewedPr>,
+}
+
+fn median(sorted: &[i64]) -> Option<i64> {
+    let mid = sorted.len() / 2;
+    match sorted.len() {
+        0 => None,
+        n if n % 2 == 1 => Some(sorted[mid]),
+        _ => Some((sorted[mid - 1] + sorted[mid]) / 2),
+    }
+}
+
+/// Nearest-rank 90th percentile
+fn p90(sorted: &[i64]) -> Option<i64> {
+    let rank = (sorted.len() * 9).div_ceil(10);
+    rank.checked_sub(1).map(|i| sorted[i])
+}
+
+/// Time from my first review request to my first review after it, for PRs
+/// first requested between local dates `start` and `end` (inclusive)
+pub fn turnaround(
+    prs: &[RequestedPr],
+    start: NaiveDate,
+    end: NaiveDate,
+    week_start: WeekStart,
+) -> ReviewTurnaround {
+    let mut seen = std::collections::HashSet::new();
+    let mut samples: Vec<(NaiveDate, i64)> = Vec::new();
+    let mut never_reviewed = Vec::new();
+
+    // The same PR can come back from more than one search
+    for pr in prs.iter().filter(|pr| seen.insert(pr.number)) {
+        let Some(requested_at) = pr.requested_at.iter().min().copied() else {
+            continue;
+        };
+        let requested_on = requested_at.with_timezone(&Local).date_naive();
+        if requested_on < start || requested_on > end {
+            continue;
+        }
+
+        match pr.reviewed_at.iter().filter(|r| **r >= requested_at).min() {
+            Some(reviewed_at) => samples.push((requested_on, (*reviewed_at - requested_at).num_seconds())),
+            None => never_reviewed.push(UnreviewedPr {
+                number: pr.number,
+                title: pr.title.clone(),
+                url: pr.url.clone(),
+                state: pr.state.clone(),
+                requested_at,
+            }),
+        }
+    }
+    never_reviewed.sort_by_key(|p| p.requested_at);
+
+    let weeks = dates::start_of_week(start, week_start)
+        .iter_weeks()
+        .take_while(|w| *w <= end)
+        .map(|week| {
+            let mut durations: Vec<i64> = samples
+                .iter()
+                .filter(|(d, _)| *d >= week && *d < week + Duration::days(7))
+                .map(|(_, s)| *s)
+                .collect();
+            durations.sort_unstable();
+            WeekTurnaround {
+                week_start: week,
+                reviews: durations.len() as u32,
+                median_seconds: median(&durations),
+                p90_seconds: p90(&durations),
+            }
+        })
+        .collect();
+
+    let mut all: Vec<i64> = samples.iter().map(|(_, s)| *s).collect();
+    all.sort_unstable();
+
+    ReviewTurnaround {
+        start,
+        end,
+        reviews: all.len() as u32,
+        median_seconds: median(&all),
+        p90_seconds: p90(&all),
+        weeks,
+        never_reviewed,
+    }
+}
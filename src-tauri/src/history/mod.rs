@@ -0,0 +1,377 @@
+use crate::backup::note_title;
+use crate::error::AppError;
+use crate::logging;
+use crate::models::AppData;
+use crate::storage;
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::fs;
+use std::path::PathBuf;
+use tauri::{AppHandle, Emitter};
+
+// ============ Undo History ============
+//
+// Each recorded save keeps the before and after versions of the entities it
+// changed: tags, tasks, notes, brag docs (entries included), reminders,
+// focus sessions and settings. Undo writes the before versions over the
+// current data, so unrelated edits made since are kept. An entity edited
+// again since the step (no longer matching its after version) is left as
+// it is and reported instead. The log lives in history.json, so it
+// survives the window closing and app restarts.
+
+/// Undo steps kept; the oldest is dropped past this
+const HISTORY_LIMIT: usize = 50;
+const HISTORY_FILE: &str = "history.json";
+const COLLECTIONS: [&str; 6] = ["tags", "tasks", "notes", "brag_docs", "notifications", "focus_sessions"];
+const SETTINGS: &str = "settings";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EntityChange {
+    /// `AppData` field the entity lives in, e.g. "tasks", or "settings"
+    collection: String,
+    id: String,
+    /// Position before the change, or after it for added entities, so a
+    /// removed entity goes back where it was
+    index: usize,
+    before: Option<Value>,
+    after: Option<Value>,
+}
+
+impl EntityChange {
+    /// The version redo (`forward`) or undo puts back
+    fn version(&self, forward: bool) -> &Option<Value> {
+        if forward {
+            &self.after
+        } else {
+            &self.before
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct HistoryEntry {
+    label: String,
+    at: DateTime<Utc>,
+    changes: Vec<EntityChange>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct History {
+    undo: VecDeque<HistoryEntry>,
+    redo: Vec<HistoryEntry>,
+}
+
+/// What undo and redo would do next, sent with `history::changed`
+#[derive(Debug, Clone, Serialize)]
+pub struct HistoryStatus {
+    pub undo_label: Option<String>,
+    pub redo_label: Option<String>,
+}
+
+/// The outcome of an undo or redo
+#[derive(Debug, Clone, Serialize)]
+pub struct StepResult {
+    /// Entities changed since the step, which were left as they are,
+    /// e.g. `task "Write docs"`
+    pub skipped: Vec<String>,
+    pub status: HistoryStatus,
+}
+
+static HISTORY: Lazy<Mutex<History>> = Lazy::new(|| Mutex::new(load()));
+
+fn history_path() -> PathBuf {
+    storage::get_app_dir().join(HISTORY_FILE)
+}
+
+fn load() -> History {
+    let Ok(contents) = fs::read_to_string(history_path()) else {
+        return History::default();
+    };
+    serde_json::from_str(&contents).unwrap_or_else(|e| {
+        logging::warn("history", &format!("Discarding unreadable undo history: {}", e));
+        History::default()
+    })
+}
+
+fn persist(history: &History) {
+    let path = history_path();
+    let temp_path = path.with_extension("json.tmp");
+    let result = serde_json::to_string(history)
+        .map_err(|e| e.to_string())
+        .and_then(|contents| fs::write(&temp_path, contents).map_err(|e| e.to_string()))
+        .and_then(|_| fs::rename(&temp_path, &path).map_err(|e| e.to_string()));
+    if let Err(e) = result {
+        logging::error("history", &format!("Failed to save undo history: {}", e));
+    }
+}
+
+fn status_of(history: &History) -> HistoryStatus {
+    HistoryStatus {
+        undo_label: history.undo.back().map(|e| e.label.clone()),
+        redo_label: history.redo.last().map(|e| e.label.clone()),
+    }
+}
+
+pub fn status() -> HistoryStatus {
+    status_of(&HISTORY.lock())
+}
+
+/// Image file names that undoing or redoing a step could bring back
+pub fn referenced_images() -> BTreeSet<String> {
+    let history = HISTORY.lock();
+    let versions = history
+        .undo
+        .iter()
+        .chain(&history.redo)
+        .flat_map(|entry| &entry.changes)
+        .flat_map(|change| change.before.iter().chain(&change.after));
+
+    let mut images = BTreeSet::new();
+    for version in versions {
+        // Notes have images; brag docs have entries with images
+        let entries = version.get("entries").and_then(Value::as_array);
+        let holders = std::iter::once(version).chain(entries.into_iter().flatten());
+        for holder in holders {
+            let references = holder.get("images").and_then(Value::as_array).into_iter().flatten();
+            images.extend(references.filter_map(Value::as_str).filter_map(storage::image_file_name));
+        }
+    }
+    images
+}
+
+// ============ Diffing ============
+
+fn entities<'a>(data: &'a Value, collection: &str) -> Vec<(&'a str, &'a Value)> {
+    data.get(collection)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|item| Some((item.get("id")?.as_str()?, item)))
+        .collect()
+}
+
+/// Entities added, removed or changed between two versions of the data
+fn diff(before: &AppData, after: &AppData) -> Vec<EntityChange> {
+    let (Ok(before), Ok(after)) = (serde_json::to_value(before), serde_json::to_value(after)) else {
+        return Vec::new();
+    };
+
+    let mut changes = Vec::new();
+    for collection in COLLECTIONS {
+        let old = entities(&before, collection);
+        let new = entities(&after, collection);
+        let old_by_id: HashMap<&str, &Value> = old.iter().copied().collect();
+        let new_by_id: HashMap<&str, &Value> = new.iter().copied().collect();
+
+        for (index, (id, value)) in old.iter().enumerate() {
+            let now = new_by_id.get(id).copied();
+            if now != Some(*value) {
+                changes.push(EntityChange {
+                    collection: collection.to_string(),
+                    id: id.to_string(),
+                    index,
+                    before: Some((*value).clone()),
+                    after: now.cloned(),
+                });
+            }
+        }
+        for (index, (id, value)) in new.iter().enumerate() {
+            if !old_by_id.contains_key(id) {
+                changes.push(EntityChange {
+                    collection: collection.to_string(),
+                    id: id.to_string(),
+                    index,
+                    before: None,
+                    after: Some((*value).clone()),
+                });
+            }
+        }
+    }
+
+    if before.get(SETTINGS) != after.get(SETTINGS) {
+        changes.push(EntityChange {
+            collection: SETTINGS.to_string(),
+            id: SETTINGS.to_string(),
+            index: 0,
+            before: before.get(SETTINGS).cloned(),
+            after: after.get(SETTINGS).cloned(),
+        });
+    }
+    changes
+}
+
+fn noun(collection: &str) -> &'static str {
+    match collection {
+        "tags" => "tag",
+        "tasks" => "task",
+        "notes" => "note",
+        "brag_docs" => "brag doc",
+        "notifications" => "reminder",
+        "focus_sessions" => "focus session",
+        _ => "item",
+    }
+}
+
+/// e.g. `task "Write docs"`, or `settings`
+fn entity_name(change: &EntityChange) -> String {
+    if change.collection == SETTINGS {
+        return SETTINGS.to_string();
+    }
+    let value = change.after.as_ref().or(change.before.as_ref());
+    let field = |name: &str| value.and_then(|v| v.get(name)).and_then(Value::as_str);
+    let title = match change.collection.as_str() {
+        "notes" => field("content").map(note_title),
+        "tags" => field("name").map(String::from),
+        _ => field("title").map(String::from),
+    };
+    match title {
+        Some(title) => format!("{} \"{}\"", noun(&change.collection), title),
+        None => noun(&change.collection).to_string(),
+    }
+}
+
+/// e.g. `Delete task "Write docs"` or `Edit 3 items`
+fn describe(changes: &[EntityChange]) -> String {
+    let [change] = changes else {
+        return format!("Edit {} items", changes.len());
+    };
+    if change.collection == SETTINGS {
+        return "Change settings".to_string();
+    }
+
+    let deleted_at = |v: &Option<Value>| v.as_ref().and_then(|v| v.get("deleted_at")).is_some_and(|d| !d.is_null());
+    let verb = match (&change.before, &change.after) {
+        (None, _) => "Add",
+        (_, None) => "Remove",
+        _ if !deleted_at(&change.before) && deleted_at(&change.after) => "Delete",
+        _ if deleted_at(&change.before) && !deleted_at(&change.after) => "Restore",
+        _ => "Edit",
+    };
+    format!("{} {}", verb, entity_name(change))
+}
+
+/// Put each change's before (undo) or after (redo) version into `data`,
+/// skipping entities that no longer match the version the step left.
+/// Returns the new data and the names of the skipped entities.
+fn apply(data: &AppData, changes: &[EntityChange], forward: bool) -> Result<(AppData, Vec<String>), AppError> {
+    let mut value = serde_json::to_value(data).map_err(|e| AppError::Internal(e.to_string()))?;
+    let mut skipped = Vec::new();
+
+    // Removals first, then the rest in ascending index order, so each
+    // reinserted entity lands back at its original index
+    let (removals, updates): (Vec<_>, Vec<_>) = changes.iter().partition(|c| c.version(forward).is_none());
+    for change in removals.into_iter().chain(updates) {
+        let target = change.version(forward).clone();
+        let expected = change.version(!forward).as_ref();
+        if change.collection == SETTINGS {
+            if value.get(SETTINGS) != expected {
+                skipped.push(entity_name(change));
+            } else if let Some(settings) = target {
+                value[SETTINGS] = settings;
+            }
+            continue;
+        }
+
+        let Some(items) = value.get_mut(&change.collection).and_then(Value::as_array_mut) else {
+            continue;
+        };
+        let position = items
+            .iter()
+            .position(|item| item.get("id").and_then(Value::as_str) == Some(change.id.as_str()));
+        if position.map(|i| &items[i]) != expected {
+            skipped.push(entity_name(change));
+            continue;
+        }
+        match (position, target) {
+            (Some(i), Some(version)) => items[i] = version,
+            (Some(i), None) => {
+                items.remove(i);
+            }
+            (None, Some(version)) => items.insert(change.index.min(items.len()), version),
+            (None, None) => {}
+        }
+    }
+
+    let data = serde_json::from_value(value).map_err(|e| AppError::Internal(format!("Failed to apply history: {}", e)))?;
+    Ok((data, skipped))
+}
+
+// ============ Recording ============
+
+fn emit_status(app: &AppHandle, history: &History) {
+    let _ = app.emit("history::changed", status_of(history));
+}
+
+/// Record a save as one undo step, labelled from its changes unless
+/// `label` is given. Clears anything that could have been redone.
+pub fn record(app: &AppHandle, label: Option<&str>, before: &AppData, after: &AppData) {
+    let changes = diff(before, after);
+    if changes.is_empty() {
+        return;
+    }
+
+    let mut history = HISTORY.lock();
+    history.undo.push_back(HistoryEntry {
+        label: label.map(String::from).unwrap_or_else(|| describe(&changes)),
+        at: Utc::now(),
+        changes,
+    });
+    while history.undo.len() > HISTORY_LIMIT {
+        history.undo.pop_front();
+    }
+    history.redo.clear();
+    persist(&history);
+    emit_status(app, &history);
+}
+
+fn step(app: &AppHandle, forward: bool) -> Result<(AppData, StepResult), AppError> {
+    let mut history = HISTORY.lock();
+    let entry = if forward { history.redo.pop() } else { history.undo.pop_back() };
+    let Some(entry) = entry else {
+        return Err(AppError::InvalidInput(
+            if forward { "Nothing to redo" } else { "Nothing to undo" }.to_string(),
+        ));
+    };
+
+    let label = entry.label.clone();
+    let applied = storage::load_data()
+        .and_then(|current| apply(&current, &entry.changes, forward))
+        .and_then(|(data, skipped)| storage::save_data(&data).map(|_| (data, skipped)));
+
+    // A step that failed stays where it was, so it can be retried
+    if forward == applied.is_ok() {
+        history.undo.push_back(entry);
+    } else {
+        history.redo.push(entry);
+    }
+    let (data, skipped) = applied?;
+
+    logging::info("history", &format!("{} {}", if forward { "Redid" } else { "Undid" }, label));
+    if !skipped.is_empty() {
+        logging::warn("history", &format!("{}: skipped {}, changed since", label, skipped.join(", ")));
+    }
+    persist(&history);
+    emit_status(app, &history);
+    let _ = app.emit("data::changed", ());
+    Ok((
+        data,
+        StepResult {
+            skipped,
+            status: status_of(&history),
+        },
+    ))
+}
+
+/// Revert the most recent recorded save
+pub fn undo(app: &AppHandle) -> Result<(AppData, StepResult), AppError> {
+    step(app, false)
+}
+
+/// Reapply the most recently undone save
+pub fn redo(app: &AppHandle) -> Result<(AppData, StepResult), AppError> {
+    step(app, true)
+}
@@ -0,0 +1,269 @@
+use crate::backup;
+use crate::error::AppError;
+use crate::history;
+use crate::logging;
+use crate::models::ImageCleanupSettings;
+use crate::storage;
+use chrono::{DateTime, Duration, Utc};
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::fs;
+use std::time::SystemTime;
+
+// ============ Saving Images ============
+//
+// Uploads are stored under the SHA-256 of their bytes, with an extension
+// from the format their bytes say they are, so pasting the same image twice
+// keeps one file. The name the frontend sends is only checked, never used.
+
+/// Largest image accepted, matching the upload limit in the frontend
+const MAX_IMAGE_BYTES: usize = 10 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImageFormat {
+    Png,
+    Jpeg,
+    Gif,
+    Webp,
+}
+
+impl ImageFormat {
+    /// Detect the format from the file's magic bytes
+    fn detect(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, ..] => Some(Self::Png),
+            [0xFF, 0xD8, 0xFF, ..] => Some(Self::Jpeg),
+            [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => Some(Self::Gif),
+            [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some(Self::Webp),
+            _ => None,
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+            Self::Gif => "gif",
+            Self::Webp => "webp",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SavedImage {
+    /// Canonical file name, `<sha256>.<ext>`
+    pub name: String,
+    /// Full path, which is what notes and brag entries store
+    pub path: String,
+    /// Whether an identical image was already saved
+    pub existing: bool,
+}
+
+/// Validate and store an uploaded image, returning its canonical name.
+/// `filename` is the name it was uploaded under.
+pub fn save(filename: &str, data: &[u8]) -> Result<SavedImage, AppError> {
+    if !storage::is_plain_file_name(filename) || filename.chars().any(char::is_control) {
+        return Err(AppError::InvalidInput(format!("Invalid image name '{}'", filename)));
+    }
+    if data.len() > MAX_IMAGE_BYTES {
+        return Err(AppError::InvalidInput("Image must be less than 10MB".to_string()));
+    }
+    let format = ImageFormat::detect(data).ok_or_else(|| {
+        AppError::InvalidInput(format!("'{}' is not a PNG, JPEG, GIF or WebP image", filename))
+    })?;
+
+    let name = format!("{}.{}", backup::sha256_hex(data), format.extension());
+    let path = storage::get_images_dir().join(&name);
+    // An existing file with the wrong size was cut short; write it again
+    let existing = fs::metadata(&path).is_ok_and(|m| m.is_file() && m.len() == data.len() as u64);
+    if !existing {
+        let path = storage::save_image(&name, data)?;
+        logging::debug("images", &format!("Saved '{}' as {}", filename, name));
+        return Ok(SavedImage {
+            name,
+            path,
+            existing: false,
+        });
+    }
+
+    // Reusing a file counts as new for cleanup, which skips recent files,
+    // so an image pasted again isn't deleted before its note is saved
+    let touched = fs::File::options()
+        .write(true)
+        .open(&path)
+        .and_then(|file| file.set_modified(SystemTime::now()));
+    if let Err(e) = touched {
+        logging::warn("images", &format!("Couldn't update the time on '{}': {}", name, e));
+    }
+    logging::debug("images", &format!("'{}' is a copy of {}", filename, name));
+    Ok(SavedImage {
+        name,
+        path: path.to_string_lossy().to_string(),
+        existing: true,
+    })
+}
+
+// ============ Unused Image Cleanup ============
+//
+// An image is in use while the current data (trash included), any backup,
+// or a step in the undo history refers to it. Anything else in the images
+// dir is reported as unused and can be deleted. Files younger than a day
+// are left alone: an image is saved as soon as it's pasted, before the
+// note or entry holding it is.
+
+const MIN_AGE_HOURS: i64 = 24;
+const CLEANUP_INTERVAL: Duration = Duration::days(1);
+const SCHEDULER_TICK_SECS: u64 = 60 * 60;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UnusedImage {
+    pub name: String,
+    pub size: u64,
+    pub modified: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImageScan {
+    /// Largest first
+    pub unused: Vec<UnusedImage>,
+    pub unused_bytes: u64,
+    /// Images on disk that something refers to
+    pub in_use: usize,
+    pub backups_scanned: usize,
+    /// Backups that couldn't be read; images only they refer to show as unused
+    pub unreadable_backups: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CleanupResult {
+    pub deleted: usize,
+    pub freed_bytes: u64,
+}
+
+static POLICY: Lazy<RwLock<ImageCleanupSettings>> = Lazy::new(|| RwLock::new(ImageCleanupSettings::default()));
+
+pub fn configure(settings: &ImageCleanupSettings) {
+    *POLICY.write() = settings.clone();
+}
+
+/// Everything the current data, backups and undo history refer to, plus
+/// the backups that couldn't be read
+fn referenced() -> Result<(BTreeSet<String>, usize, Vec<String>), AppError> {
+    let mut images = storage::referenced_images(&storage::load_data()?);
+    images.extend(history::referenced_images());
+
+    let backups = backup::list();
+    let mut unreadable = Vec::new();
+    for entry in &backups {
+        match backup::referenced_images(&entry.name) {
+            Ok(names) => images.extend(names),
+            Err(e) => {
+                logging::warn("images", &format!("Couldn't read backup '{}': {}", entry.name, e));
+                unreadable.push(entry.name.clone());
+            }
+        }
+    }
+    Ok((images, backups.len(), unreadable))
+}
+
+/// List unused images without deleting anything
+pub fn scan() -> Result<ImageScan, AppError> {
+    let (referenced, backups_scanned, unreadable_backups) = referenced()?;
+    let cutoff = SystemTime::from(Utc::now() - Duration::hours(MIN_AGE_HOURS));
+
+    let dir = fs::read_dir(storage::get_images_dir())
+        .map_err(|e| AppError::Storage(format!("Failed to read images directory: {}", e)))?;
+    let mut unused = Vec::new();
+    let mut in_use = 0;
+    for entry in dir.flatten() {
+        let Ok(metadata) = entry.metadata() else { continue };
+        let Some(name) = entry.file_name().to_str().map(String::from) else { continue };
+        if !metadata.is_file() || name.starts_with('.') {
+            continue;
+        }
+        if referenced.contains(&name) {
+            in_use += 1;
+            continue;
+        }
+        let modified = metadata.modified().ok();
+        if modified.is_some_and(|m| m > cutoff) {
+            continue;
+        }
+        unused.push(UnusedImage {
+            name,
+            size: metadata.len(),
+            modified: modified.map(DateTime::<Utc>::from),
+        });
+    }
+    unused.sort_by_key(|image| std::cmp::Reverse(image.size));
+
+    Ok(ImageScan {
+        unused_bytes: unused.iter().map(|image| image.size).sum(),
+        unused,
+        in_use,
+        backups_scanned,
+        unreadable_backups,
+    })
+}
+
+/// Delete the named images, skipping any that are no longer unused
+pub fn delete_unused(names: &[String]) -> Result<CleanupResult, AppError> {
+    let scan = scan()?;
+    let mut result = CleanupResult {
+        deleted: 0,
+        freed_bytes: 0,
+    };
+    for image in scan.unused.iter().filter(|image| names.contains(&image.name)) {
+        match storage::delete_image(&image.name) {
+            Ok(()) => {
+                result.deleted += 1;
+                result.freed_bytes += image.size;
+            }
+            Err(e) => logging::warn("images", &format!("Failed to delete '{}': {}", image.name, e)),
+        }
+    }
+
+    if result.deleted > 0 {
+        logging::info(
+            "images",
+            &format!("Deleted {} unused images ({} bytes)", result.deleted, result.freed_bytes),
+        );
+    }
+    Ok(result)
+}
+
+// ============ Scheduled Cleanup ============
+
+/// Delete everything unused, unless a backup couldn't be read and might
+/// still need some of it
+fn run_cleanup() -> Result<(), AppError> {
+    let scan = scan()?;
+    if !scan.unreadable_backups.is_empty() {
+        logging::warn("images", "Skipping scheduled cleanup; some backups couldn't be read");
+        return Ok(());
+    }
+    let names: Vec<String> = scan.unused.into_iter().map(|image| image.name).collect();
+    if !names.is_empty() {
+        delete_unused(&names)?;
+    }
+    Ok(())
+}
+
+/// Check hourly, cleaning up once a day while scheduled cleanup is on
+pub fn start_scheduler() {
+    std::thread::spawn(|| {
+        let mut last_run: Option<DateTime<Utc>> = None;
+        loop {
+            let due = last_run.is_none_or(|last| Utc::now() - last >= CLEANUP_INTERVAL);
+            if POLICY.read().scheduled && due {
+                if let Err(e) = run_cleanup() {
+                    logging::error("images", &format!("Scheduled cleanup failed: {}", e));
+                }
+                last_run = Some(Utc::now());
+            }
+            std::thread::sleep(std::time::Duration::from_secs(SCHEDULER_TICK_SECS));
+        }
+    });
+}
@@ -1,6 +1,23 @@
+mod backup;
+mod brag;
+mod capture;
 mod commands;
+mod dates;
+mod diagnostics;
+mod error;
+mod export;
+mod focus;
+mod github_stats;
+mod history;
+mod images;
+mod logging;
 mod models;
+mod review;
+mod shortcuts;
+mod stats;
 mod storage;
+mod timer;
+mod trash;
 
 use tauri::{
     menu::{Menu, MenuItem},
@@ -8,7 +25,9 @@ use tauri::{
     Emitter, Manager, WindowEvent,
 };
 use tauri_plugin_autostart::MacosLauncher;
-use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Modifiers, Shortcut, ShortcutState};
+
+/// Id of the menu bar icon, used to update its title
+const TRAY_ID: &str = "main";
 
 fn toggle_window_visibility(app: &tauri::AppHandle) {
     if let Some(window) = app.get_webview_window("main") {
@@ -21,6 +40,18 @@ fn toggle_window_visibility(app: &tauri::AppHandle) {
     }
 }
 
+fn toggle_quick_capture(app: &tauri::AppHandle) {
+    if let Some(window) = app.get_webview_window("quick-capture") {
+        if window.is_visible().unwrap_or(false) {
+            let _ = window.hide();
+        } else {
+            let _ = window.center();
+            let _ = window.show();
+            let _ = window.set_focus();
+        }
+    }
+}
+
 fn show_window(app: &tauri::AppHandle) {
     if let Some(window) = app.get_webview_window("main") {
         let _ = window.show();
@@ -30,25 +61,19 @@ fn show_window(app: &tauri::AppHandle) {
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    // Create the global shortcut for Cmd+Shift+B
-    let shortcut = Shortcut::new(Some(Modifiers::SUPER | Modifiers::SHIFT), Code::KeyB);
-
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_clipboard_manager::init())
         .plugin(tauri_plugin_autostart::init(
             MacosLauncher::LaunchAgent,
             Some(vec!["--hidden"]),
         ))
         .plugin(
             tauri_plugin_global_shortcut::Builder::new()
-                .with_handler(move |app, key, event| {
-                    if key == &shortcut && event.state == ShortcutState::Pressed {
-                        toggle_window_visibility(app);
-                    }
-                })
+                .with_handler(shortcuts::handle)
                 .build(),
         )
         .plugin(tauri_plugin_single_instance::init(|app, _args, _cwd| {
@@ -61,25 +86,47 @@ pub fn run() {
         .setup(|app| {
             // Ensure data directories exist
             if let Err(e) = storage::ensure_directories() {
-                eprintln!("Failed to create data directories: {}", e);
+                logging::error("app", &format!("Failed to create data directories: {}", e));
             }
 
-            // Create daily backup on startup
-            if let Err(e) = storage::create_backup() {
-                eprintln!("Failed to create backup: {}", e);
+            // Apply log filters, backup, trash and image cleanup policy, then start the next brag doc
+            // once the review cycle rolls over
+            match storage::load_data() {
+                Ok(mut data) => {
+                    logging::configure(&data.settings.logging);
+                    backup::configure(&data.settings.backups);
+                    trash::configure(&data.settings.trash);
+                    images::configure(&data.settings.image_cleanup);
+                    let today = chrono::Local::now().date_naive();
+                    if let Some(title) = brag::rollover(&mut data, today) {
+                        if let Err(e) = storage::save_data(&data) {
+                            logging::error("app", &format!("Failed to save brag doc '{}': {}", title, e));
+                        }
+                    }
+                }
+                Err(e) => logging::error("app", &format!("Failed to load data: {}", e)),
             }
 
+            // Back up on startup if due, then on the configured interval
+            backup::start_scheduler();
+
+            // Purge items that have been in the trash too long, hourly
+            trash::start_scheduler(app.handle().clone());
+
+            // Delete unused images daily, if turned on in Settings
+            images::start_scheduler();
+
             // Hide from dock on macOS
             #[cfg(target_os = "macos")]
             {
                 app.set_activation_policy(tauri::ActivationPolicy::Accessory);
             }
 
-            // Register the global shortcut
-            let shortcut = Shortcut::new(Some(Modifiers::SUPER | Modifiers::SHIFT), Code::KeyB);
-            if let Err(e) = app.global_shortcut().register(shortcut) {
-                eprintln!("Failed to register global shortcut: {}", e);
-            }
+            // Register global shortcuts from settings
+            let shortcut_settings = storage::load_data()
+                .map(|data| data.settings.shortcuts)
+                .unwrap_or_default();
+            shortcuts::register_all(app.handle(), &shortcut_settings);
 
             // Setup macOS sleep/wake notifications
             #[cfg(target_os = "macos")]
@@ -92,11 +139,18 @@ pub fn run() {
 
             // Create tray menu
             let show_item = MenuItem::with_id(app, "show", "Show Window", true, None::<&str>)?;
+            let clipboard_task_item = MenuItem::with_id(
+                app,
+                "task_from_clipboard",
+                "New Task from Clipboard",
+                true,
+                None::<&str>,
+            )?;
             let quit_item = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
-            let menu = Menu::with_items(app, &[&show_item, &quit_item])?;
+            let menu = Menu::with_items(app, &[&show_item, &clipboard_task_item, &quit_item])?;
 
             // Build tray icon
-            let _tray = TrayIconBuilder::new()
+            let _tray = TrayIconBuilder::with_id(TRAY_ID)
                 .icon(app.default_window_icon().unwrap().clone())
                 .menu(&menu)
                 .show_menu_on_left_click(false)
@@ -104,6 +158,9 @@ pub fn run() {
                     "show" => {
                         show_window(app);
                     }
+                    "task_from_clipboard" => {
+                        shortcuts::task_from_clipboard(app);
+                    }
                     "quit" => {
                         app.exit(0);
                     }
@@ -138,9 +195,22 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             commands::get_all_data,
             commands::save_all_data,
+            commands::undo,
+            commands::redo,
+            commands::get_history_status,
             commands::create_backup,
             commands::get_backups,
+            commands::preview_backup,
             commands::restore_backup,
+            commands::get_backup_items,
+            commands::restore_backup_items,
+            commands::trash_item,
+            commands::get_trash,
+            commands::restore_trash_item,
+            commands::purge_trash_item,
+            commands::empty_trash,
+            commands::scan_images,
+            commands::delete_unused_images,
             commands::save_image,
             commands::delete_image,
             commands::get_app_data_path,
@@ -154,7 +224,30 @@ pub fn run() {
             commands::fetch_my_changes_requested_prs,
             commands::fetch_my_needs_review_prs,
             commands::fetch_github_stats,
+            commands::get_github_stats,
+            commands::get_review_turnaround,
             commands::invalidate_pr_cache,
+            commands::quick_capture,
+            commands::parse_schedule,
+            commands::get_shortcut_status,
+            commands::create_task_from_url,
+            commands::create_task_from_clipboard,
+            commands::export_brag_doc,
+            commands::suggest_brag_entries,
+            commands::get_brag_summary,
+            commands::generate_weekly_review,
+            commands::save_weekly_review_note,
+            commands::schedule_weekly_review_reminder,
+            commands::start_timer,
+            commands::stop_timer,
+            commands::get_running_timer,
+            commands::get_time_totals,
+            commands::start_focus,
+            commands::stop_focus,
+            commands::get_focus_status,
+            commands::get_productivity_stats,
+            commands::get_recent_logs,
+            commands::run_diagnostics,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -217,10 +310,14 @@ fn setup_sleep_wake_listener(app_handle: tauri::AppHandle) {
 
         match message_type {
             K_IO_MESSAGE_SYSTEM_HAS_POWERED_ON => {
-                // System woke from sleep - emit event to frontend
+                // System woke from sleep - resume a paused timer and emit event to frontend
+                timer::resume_after_wake(&ctx.app_handle);
                 let _ = ctx.app_handle.emit("system-wake", ());
             }
             K_IO_MESSAGE_SYSTEM_WILL_SLEEP => {
+                // Stop the running timer so time asleep isn't tracked
+                timer::pause_for_sleep(&ctx.app_handle);
+
                 // Allow the sleep to proceed
                 unsafe {
                     IOAllowPowerChange(ctx.root_port, message_argument as isize);
@@ -256,7 +353,7 @@ fn setup_sleep_wake_listener(app_handle: tauri::AppHandle) {
         );
 
         if root_port == 0 {
-            eprintln!("Failed to register for system power notifications");
+            logging::error("app", "Failed to register for system power notifications");
             let _ = Box::from_raw(ctx_ptr);
             return;
         }
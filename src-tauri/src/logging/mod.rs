@@ -0,0 +1,201 @@
+use crate::models::LogSettings;
+use crate::storage;
+use chrono::Local;
+use once_cell::sync::Lazy;
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+// ============ Logging ============
+//
+// Lines go to logs/atulify.log under the app dir (and stderr) as
+// `[timestamp] LEVEL module: message`. The file rotates at MAX_LOG_BYTES,
+// keeping atulify.1.log (newest) through atulify.{MAX_ROTATED}.log.
+
+const LOGS_DIR: &str = "logs";
+const LOG_FILE: &str = "atulify.log";
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+const MAX_ROTATED: usize = 5;
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.3f";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+}
+
+impl LogLevel {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Error => "ERROR",
+            Self::Warn => "WARN",
+            Self::Info => "INFO",
+            Self::Debug => "DEBUG",
+        }
+    }
+
+    fn from_label(label: &str) -> Option<Self> {
+        match label {
+            "ERROR" => Some(Self::Error),
+            "WARN" => Some(Self::Warn),
+            "INFO" => Some(Self::Info),
+            "DEBUG" => Some(Self::Debug),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LogEntry {
+    pub timestamp: String,
+    pub level: LogLevel,
+    pub module: String,
+    pub message: String,
+}
+
+static CONFIG: Lazy<RwLock<LogSettings>> = Lazy::new(|| RwLock::new(LogSettings::default()));
+
+// Serializes writes so rotation can't interleave with another line
+static WRITER: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+pub fn get_logs_dir() -> PathBuf {
+    storage::get_app_dir().join(LOGS_DIR)
+}
+
+fn log_path(index: usize) -> PathBuf {
+    match index {
+        0 => get_logs_dir().join(LOG_FILE),
+        n => get_logs_dir().join(format!("atulify.{}.log", n)),
+    }
+}
+
+/// Apply the level and per-module filters from settings
+pub fn configure(settings: &LogSettings) {
+    *CONFIG.write() = settings.clone();
+}
+
+fn enabled(level: LogLevel, module: &str) -> bool {
+    let config = CONFIG.read();
+    level <= config.modules.get(module).copied().unwrap_or(config.level)
+}
+
+fn rotate() -> std::io::Result<()> {
+    let _ = fs::remove_file(log_path(MAX_ROTATED));
+    for index in (0..MAX_ROTATED).rev() {
+        let from = log_path(index);
+        if from.exists() {
+            fs::rename(&from, log_path(index + 1))?;
+        }
+    }
+    Ok(())
+}
+
+fn write_line(line: &str) -> std::io::Result<()> {
+    let _guard = WRITER.lock();
+    fs::create_dir_all(get_logs_dir())?;
+
+    let path = log_path(0);
+    if fs::metadata(&path).map(|m| m.len() >= MAX_LOG_BYTES).unwrap_or(false) {
+        rotate()?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+    writeln!(file, "{}", line)
+}
+
+pub fn log(level: LogLevel, module: &str, message: &str) {
+    if !enabled(level, module) {
+        return;
+    }
+
+    let line = format!("[{}] {} {}: {}", Local::now().format(TIMESTAMP_FORMAT), level, module, message);
+    eprintln!("{}", line);
+    // Nowhere left to report a failure to write the log itself
+    let _ = write_line(&line);
+}
+
+pub fn error(module: &str, message: &str) {
+    log(LogLevel::Error, module, message);
+}
+
+pub fn warn(module: &str, message: &str) {
+    log(LogLevel::Warn, module, message);
+}
+
+pub fn info(module: &str, message: &str) {
+    log(LogLevel::Info, module, message);
+}
+
+pub fn debug(module: &str, message: &str) {
+    log(LogLevel::Debug, module, message);
+}
+
+// ============ Reading Logs ============
+
+fn parse_line(line: &str) -> Option<LogEntry> {
+    let rest = line.strip_prefix('[')?;
+    let (timestamp, rest) = rest.split_once("] ")?;
+    let (level, rest) = rest.split_once(' ')?;
+    let (module, message) = rest.split_once(": ")?;
+    Some(LogEntry {
+        timestamp: timestamp.to_string(),
+        level: LogLevel::from_label(level)?,
+        module: module.to_string(),
+        message: message.to_string(),
+    })
+}
+
+fn read_entries(path: &PathBuf) -> Vec<LogEntry> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+
+    let mut entries: Vec<LogEntry> = Vec::new();
+    for line in contents.lines() {
+        match parse_line(line) {
+            Some(entry) => entries.push(entry),
+            // Multi-line messages (e.g. gh stderr) continue the previous entry
+            None => {
+                if let Some(last) = entries.last_mut() {
+                    last.message.push('\n');
+                    last.message.push_str(line);
+                }
+            }
+        }
+    }
+    entries
+}
+
+/// The most recent `limit` entries at `min_level` or more severe, newest first
+pub fn recent(limit: usize, min_level: LogLevel) -> Vec<LogEntry> {
+    let _guard = WRITER.lock();
+    let mut recent = Vec::new();
+
+    for index in 0..=MAX_ROTATED {
+        let path = log_path(index);
+        if !path.exists() {
+            continue;
+        }
+        let entries = read_entries(&path);
+        recent.extend(entries.into_iter().rev().filter(|e| e.level <= min_level));
+        if recent.len() >= limit {
+            break;
+        }
+    }
+
+    recent.truncate(limit);
+    recent
+}
@@ -1,9 +1,11 @@
+use crate::dates::WeekStart;
+use crate::logging::LogLevel;
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
-// Predefined tag colors (available for future use)
-#[allow(dead_code)]
+// Predefined tag colors, assigned round-robin to tags created from quick capture
 pub const TAG_COLORS: &[&str] = &[
     "#E53E3E", // Red
     "#DD6B20", // Orange
@@ -27,7 +29,6 @@ pub struct Tag {
 }
 
 impl Tag {
-    #[allow(dead_code)]
     pub fn new(name: String, color: String) -> Self {
         Self {
             id: Uuid::new_v4().to_string(),
@@ -54,7 +55,6 @@ pub struct ResourceLink {
 }
 
 impl ResourceLink {
-    #[allow(dead_code)]
     pub fn new(url: String, label: String, link_type: LinkType) -> Self {
         Self {
             id: Uuid::new_v4().to_string(),
@@ -99,6 +99,13 @@ pub struct GitHubPr {
     pub requested_reviewers: Vec<String>,
 }
 
+/// Time spent on a task; `ended_at` is None while the timer is running
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeInterval {
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
     pub id: String,
@@ -114,10 +121,14 @@ pub struct Task {
     pub task_type: TaskType,
     pub task_url: Option<String>,
     pub pr_approvals: Option<Vec<PrApproval>>,
+    #[serde(default)]
+    pub time_intervals: Vec<TimeInterval>,
+    /// Set while the task is in the trash
+    #[serde(default)]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl Task {
-    #[allow(dead_code)]
     pub fn new(title: String) -> Self {
         Self {
             id: Uuid::new_v4().to_string(),
@@ -132,8 +143,15 @@ impl Task {
             task_type: TaskType::default(),
             task_url: None,
             pr_approvals: None,
+            time_intervals: Vec::new(),
+            deleted_at: None,
         }
     }
+
+    /// Whether this task's timer is running
+    pub fn timer_running(&self) -> bool {
+        self.time_intervals.iter().any(|i| i.ended_at.is_none())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -145,10 +163,11 @@ pub struct Note {
     pub tag_ids: Vec<String>,
     pub linked_task_ids: Vec<String>,
     pub images: Vec<String>,
+    #[serde(default)]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl Note {
-    #[allow(dead_code)]
     pub fn new(content: String) -> Self {
         let now = Utc::now();
         Self {
@@ -159,6 +178,7 @@ impl Note {
             tag_ids: Vec::new(),
             linked_task_ids: Vec::new(),
             images: Vec::new(),
+            deleted_at: None,
         }
     }
 }
@@ -171,6 +191,20 @@ pub struct BragEntry {
     pub date: NaiveDate,
     pub images: Vec<String>,
     pub links: Vec<String>,
+    #[serde(default)]
+    pub tag_ids: Vec<String>,
+    /// One of `Settings::brag_categories`, e.g. "Impact"
+    #[serde(default)]
+    pub category: Option<String>,
+    /// Suggested or unfinished entries; carried into the next doc on rollover
+    #[serde(default)]
+    pub draft: bool,
+    /// When the entry was written, as opposed to `date`, when the work
+    /// happened. None for entries saved before this was recorded.
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl BragEntry {
@@ -183,6 +217,11 @@ impl BragEntry {
             date,
             images: Vec::new(),
             links: Vec::new(),
+            tag_ids: Vec::new(),
+            category: None,
+            draft: false,
+            created_at: Some(Utc::now()),
+            deleted_at: None,
         }
     }
 }
@@ -217,6 +256,14 @@ pub enum ScheduleType {
     Weekly,
 }
 
+/// Work done when a notification fires, in addition to showing it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationAction {
+    /// Generate this week's review and save it as a note
+    WeeklyReview,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Notification {
     pub id: String,
@@ -227,10 +274,13 @@ pub struct Notification {
     pub date: Option<NaiveDate>, // For one_off notifications
     pub day_of_week: Option<u8>, // 0-6 for weekly (Sunday = 0)
     pub enabled: bool,
+    #[serde(default)]
+    pub action: Option<NotificationAction>,
+    #[serde(default)]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl Notification {
-    #[allow(dead_code)]
     pub fn new(title: String, message: String, schedule_type: ScheduleType, time: String) -> Self {
         Self {
             id: Uuid::new_v4().to_string(),
@@ -241,6 +291,8 @@ impl Notification {
             date: None,
             day_of_week: None,
             enabled: true,
+            action: None,
+            deleted_at: None,
         }
     }
 }
@@ -259,6 +311,131 @@ impl Default for Theme {
     }
 }
 
+/// Global shortcut accelerators, in the format accepted by the
+/// global-shortcut plugin (e.g. "CmdOrCtrl+Shift+N"). Empty means unbound.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutSettings {
+    pub toggle_window: String,
+    pub quick_capture: String,
+    pub open_today: String,
+    pub review_clipboard_pr: String,
+    pub task_from_clipboard: String,
+}
+
+impl Default for ShortcutSettings {
+    fn default() -> Self {
+        Self {
+            toggle_window: "Super+Shift+B".to_string(),
+            quick_capture: "Super+Shift+N".to_string(),
+            open_today: String::new(),
+            review_clipboard_pr: String::new(),
+            task_from_clipboard: String::new(),
+        }
+    }
+}
+
+/// Pomodoro phase lengths
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FocusSettings {
+    pub work_minutes: u32,
+    pub short_break_minutes: u32,
+    pub long_break_minutes: u32,
+    /// Work sessions between long breaks
+    pub sessions_before_long_break: u32,
+}
+
+impl Default for FocusSettings {
+    fn default() -> Self {
+        Self {
+            work_minutes: 25,
+            short_break_minutes: 5,
+            long_break_minutes: 15,
+            sessions_before_long_break: 4,
+        }
+    }
+}
+
+/// How often backups are taken and how many are kept in each tier. The
+/// newest backup of each hour, day and ISO week is kept, for the most
+/// recent `hourly` hours, `daily` days and `weekly` weeks that have one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackupSettings {
+    pub interval_minutes: u32,
+    pub hourly: u32,
+    pub daily: u32,
+    pub weekly: u32,
+}
+
+impl Default for BackupSettings {
+    fn default() -> Self {
+        Self {
+            interval_minutes: 60,
+            hourly: 24,
+            daily: 14,
+            weekly: 12,
+        }
+    }
+}
+
+/// Days a deleted item stays in the trash before it's purged; 0 keeps
+/// trashed items until they're purged by hand
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrashSettings {
+    pub retention_days: u32,
+}
+
+impl Default for TrashSettings {
+    fn default() -> Self {
+        Self { retention_days: 30 }
+    }
+}
+
+/// Whether images nothing refers to are deleted daily instead of only on
+/// request from Settings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImageCleanupSettings {
+    pub scheduled: bool,
+}
+
+/// Minimum level written to the log, with overrides per module
+/// (e.g. "commands", "storage", "github")
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogSettings {
+    pub level: LogLevel,
+    pub modules: HashMap<String, LogLevel>,
+}
+
+/// Month and day a review period starts on, recurring every year
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct CycleStart {
+    pub month: u32,
+    pub day: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ReviewCycle {
+    Quarterly,
+    HalfYearly,
+    /// Each period runs from one start to the day before the next
+    Custom { starts: Vec<CycleStart> },
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReviewCycleSettings {
+    /// `None` leaves brag docs to be created by hand
+    pub cycle: Option<ReviewCycle>,
+    /// Move draft entries from the previous doc into the one created on rollover
+    pub carry_over_drafts: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Settings {
     pub theme: Theme,
@@ -266,6 +443,39 @@ pub struct Settings {
     pub launch_at_login: bool,
     pub user_name: String,
     pub onboarding_complete: bool,
+    #[serde(default)]
+    pub shortcuts: ShortcutSettings,
+    /// First day of the week for date parsing; `None` follows the system locale
+    #[serde(default)]
+    pub week_start: Option<WeekStart>,
+    /// Categories offered for brag entries, in display order
+    #[serde(default = "default_brag_categories")]
+    pub brag_categories: Vec<String>,
+    #[serde(default)]
+    pub review_cycle: ReviewCycleSettings,
+    #[serde(default)]
+    pub focus: FocusSettings,
+    #[serde(default)]
+    pub logging: LogSettings,
+    #[serde(default)]
+    pub backups: BackupSettings,
+    #[serde(default)]
+    pub trash: TrashSettings,
+    #[serde(default)]
+    pub image_cleanup: ImageCleanupSettings,
+}
+
+fn default_brag_categories() -> Vec<String> {
+    ["Impact", "Collaboration", "Craft", "Leadership"]
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+impl Settings {
+    pub fn resolved_week_start(&self) -> WeekStart {
+        self.week_start.unwrap_or_else(WeekStart::from_system)
+    }
 }
 
 impl Default for Settings {
@@ -276,10 +486,31 @@ impl Default for Settings {
             launch_at_login: false,
             user_name: String::new(),
             onboarding_complete: false,
+            shortcuts: ShortcutSettings::default(),
+            week_start: None,
+            brag_categories: default_brag_categories(),
+            review_cycle: ReviewCycleSettings::default(),
+            focus: FocusSettings::default(),
+            logging: LogSettings::default(),
+            backups: BackupSettings::default(),
+            trash: TrashSettings::default(),
+            image_cleanup: ImageCleanupSettings::default(),
         }
     }
 }
 
+/// A logged pomodoro work phase
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FocusSession {
+    pub id: String,
+    pub task_id: String,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub planned_minutes: u32,
+    /// False when the session was stopped before the work phase ended
+    pub completed: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct AppData {
     pub tags: Vec<Tag>,
@@ -288,4 +519,6 @@ pub struct AppData {
     pub brag_docs: Vec<BragDoc>,
     pub notifications: Vec<Notification>,
     pub settings: Settings,
+    #[serde(default)]
+    pub focus_sessions: Vec<FocusSession>,
 }
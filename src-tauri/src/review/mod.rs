@@ -0,0 +1,158 @@
+use crate::models::AppData;
+use chrono::{Duration, Local, NaiveDate};
+use serde::Serialize;
+
+// ============ Weekly Review ============
+
+/// A task, note, or brag entry listed in a review
+#[derive(Debug, Clone, Serialize)]
+pub struct ReviewItem {
+    pub id: String,
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReviewPr {
+    pub number: u64,
+    pub title: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WeeklyReview {
+    pub week_start: NaiveDate,
+    pub week_end: NaiveDate,
+    pub tasks_completed: Vec<ReviewItem>,
+    /// Open tasks scheduled on or before the last day of the week
+    pub tasks_carried_over: Vec<ReviewItem>,
+    pub notes_written: Vec<ReviewItem>,
+    pub prs_merged: Vec<ReviewPr>,
+    pub prs_reviewed: Vec<ReviewPr>,
+    /// Entries written during the week, whatever date they're for; older
+    /// entries without a creation time count by their date
+    pub brag_entries_added: Vec<ReviewItem>,
+    /// Set when PRs couldn't be fetched; the PR sections are then empty
+    pub github_error: Option<String>,
+    pub markdown: String,
+}
+
+/// First line of a note without its Markdown heading marker
+fn note_title(content: &str) -> String {
+    content
+        .lines()
+        .map(|line| line.trim_start_matches('#').trim())
+        .find(|line| !line.is_empty())
+        .unwrap_or("Untitled note")
+        .to_string()
+}
+
+/// Build the review for the week starting on `week_start`
+pub fn build(
+    data: &AppData,
+    week_start: NaiveDate,
+    prs_merged: Vec<ReviewPr>,
+    prs_reviewed: Vec<ReviewPr>,
+    github_error: Option<String>,
+) -> WeeklyReview {
+    let week_end = week_start + Duration::days(6);
+    let in_week = |date: NaiveDate| date >= week_start && date <= week_end;
+    let local_date = |dt: chrono::DateTime<chrono::Utc>| dt.with_timezone(&Local).date_naive();
+
+    let tasks_completed = data
+        .tasks
+        .iter()
+        .filter(|t| t.completed && t.completed_at.is_some_and(|c| in_week(local_date(c))))
+        .map(|t| ReviewItem {
+            id: t.id.clone(),
+            title: t.title.clone(),
+        })
+        .collect();
+
+    let tasks_carried_over = data
+        .tasks
+        .iter()
+        .filter(|t| !t.completed && !t.archived && t.scheduled_date.is_some_and(|d| d <= week_end))
+        .map(|t| ReviewItem {
+            id: t.id.clone(),
+            title: t.title.clone(),
+        })
+        .collect();
+
+    let notes_written = data
+        .notes
+        .iter()
+        .filter(|n| in_week(local_date(n.created_at)))
+        .map(|n| ReviewItem {
+            id: n.id.clone(),
+            title: note_title(&n.content),
+        })
+        .collect();
+
+    let brag_entries_added = data
+        .brag_docs
+        .iter()
+        .flat_map(|doc| doc.entries.iter())
+        .filter(|e| in_week(e.created_at.map_or(e.date, local_date)))
+        .map(|e| ReviewItem {
+            id: e.id.clone(),
+            title: e.title.clone(),
+        })
+        .collect();
+
+    let mut review = WeeklyReview {
+        week_start,
+        week_end,
+        tasks_completed,
+        tasks_carried_over,
+        notes_written,
+        prs_merged,
+        prs_reviewed,
+        brag_entries_added,
+        github_error,
+        markdown: String::new(),
+    };
+    review.markdown = to_markdown(&review);
+    review
+}
+
+fn push_items(md: &mut String, heading: &str, items: &[ReviewItem]) {
+    md.push_str(&format!("## {} ({})\n\n", heading, items.len()));
+    if items.is_empty() {
+        md.push_str("_None_\n");
+    }
+    for item in items {
+        md.push_str(&format!("- {}\n", item.title));
+    }
+    md.push('\n');
+}
+
+fn push_prs(md: &mut String, heading: &str, prs: &[ReviewPr], github_error: Option<&str>) {
+    md.push_str(&format!("## {} ({})\n\n", heading, prs.len()));
+    match github_error {
+        Some(_) => md.push_str("_Couldn't load from GitHub_\n"),
+        None if prs.is_empty() => md.push_str("_None_\n"),
+        None => {}
+    }
+    for pr in prs {
+        md.push_str(&format!("- [#{} {}]({})\n", pr.number, pr.title, pr.url));
+    }
+    md.push('\n');
+}
+
+pub fn to_markdown(review: &WeeklyReview) -> String {
+    let mut md = format!(
+        "# Weekly Review: {} - {}\n\n",
+        review.week_start.format("%b %-d"),
+        review.week_end.format("%b %-d, %Y")
+    );
+
+    let github_error = review.github_error.as_deref();
+    push_items(&mut md, "Tasks Completed", &review.tasks_completed);
+    push_items(&mut md, "Carried Over", &review.tasks_carried_over);
+    push_items(&mut md, "Notes Written", &review.notes_written);
+    push_prs(&mut md, "PRs Merged", &review.prs_merged, github_error);
+    push_prs(&mut md, "PRs Reviewed", &review.prs_reviewed, github_error);
+    push_items(&mut md, "Brag Entries Added", &review.brag_entries_added);
+
+    md.trim_end().to_string() + "\n"
+}
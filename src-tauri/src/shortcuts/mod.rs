@@ -0,0 +1,230 @@
+use crate::logging;
+use crate::models::ShortcutSettings;
+use once_cell::sync::Lazy;
+use parking_lot::{Mutex, RwLock};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutEvent, ShortcutState};
+use tauri_plugin_notification::NotificationExt;
+
+// ============ Global Shortcuts ============
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShortcutAction {
+    ToggleWindow,
+    QuickCapture,
+    OpenToday,
+    ReviewClipboardPr,
+    TaskFromClipboard,
+}
+
+impl ShortcutAction {
+    const ALL: [ShortcutAction; 5] = [
+        Self::ToggleWindow,
+        Self::QuickCapture,
+        Self::OpenToday,
+        Self::ReviewClipboardPr,
+        Self::TaskFromClipboard,
+    ];
+
+    fn accelerator(self, settings: &ShortcutSettings) -> &str {
+        match self {
+            Self::ToggleWindow => &settings.toggle_window,
+            Self::QuickCapture => &settings.quick_capture,
+            Self::OpenToday => &settings.open_today,
+            Self::ReviewClipboardPr => &settings.review_clipboard_pr,
+            Self::TaskFromClipboard => &settings.task_from_clipboard,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::ToggleWindow => "Toggle window",
+            Self::QuickCapture => "Quick capture",
+            Self::OpenToday => "Open Today view",
+            Self::ReviewClipboardPr => "Review PR from clipboard",
+            Self::TaskFromClipboard => "New task from clipboard",
+        }
+    }
+}
+
+/// Registration outcome for one action, reported back to the UI
+#[derive(Debug, Clone, Serialize)]
+pub struct ShortcutStatus {
+    pub action: ShortcutAction,
+    pub accelerator: String,
+    pub registered: bool,
+    pub error: Option<String>,
+}
+
+struct ShortcutRegistry {
+    /// Shortcut id -> action, consulted by the plugin handler
+    bindings: HashMap<u32, ShortcutAction>,
+    registered: Vec<Shortcut>,
+    settings: Option<ShortcutSettings>,
+    statuses: Vec<ShortcutStatus>,
+}
+
+static REGISTRY: Lazy<RwLock<ShortcutRegistry>> = Lazy::new(|| {
+    RwLock::new(ShortcutRegistry {
+        bindings: HashMap::new(),
+        registered: Vec::new(),
+        settings: None,
+        statuses: Vec::new(),
+    })
+});
+
+/// Held for a whole re-registration, so two can't interleave their
+/// unregister and register calls
+static REGISTER_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+/// Bumped on every sync so a queued, outdated one notices and skips
+static SYNC_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Replace all registered shortcuts with the ones in `settings`.
+///
+/// Invalid, duplicate, or already-taken accelerators don't stop the others
+/// from registering; each failure is reported in the returned statuses and
+/// emitted as a `shortcuts::status` event.
+pub fn register_all(app: &AppHandle, settings: &ShortcutSettings) -> Vec<ShortcutStatus> {
+    let _guard = REGISTER_LOCK.lock();
+    register_locked(app, settings)
+}
+
+fn register_locked(app: &AppHandle, settings: &ShortcutSettings) -> Vec<ShortcutStatus> {
+    let manager = app.global_shortcut();
+
+    // Take the old shortcuts out without holding the lock while the plugin
+    // hops to the main thread, where the shortcut handler also reads it
+    let previous = std::mem::take(&mut REGISTRY.write().registered);
+    for shortcut in previous {
+        let _ = manager.unregister(shortcut);
+    }
+
+    let mut bindings: HashMap<u32, ShortcutAction> = HashMap::new();
+    let mut registered: Vec<Shortcut> = Vec::new();
+    let mut statuses: Vec<ShortcutStatus> = Vec::new();
+
+    for action in ShortcutAction::ALL {
+        let accelerator = action.accelerator(settings).trim().to_string();
+        let mut status = ShortcutStatus {
+            action,
+            accelerator: accelerator.clone(),
+            registered: false,
+            error: None,
+        };
+
+        // An empty accelerator leaves the action unbound
+        if !accelerator.is_empty() {
+            match accelerator.parse::<Shortcut>() {
+                Err(e) => status.error = Some(format!("Invalid shortcut: {}", e)),
+                Ok(shortcut) => {
+                    if let Some(other) = bindings.get(&shortcut.id()) {
+                        status.error = Some(format!("Already used by \"{}\"", other.label()));
+                    } else if let Err(e) = manager.register(shortcut) {
+                        status.error = Some(format!(
+                            "Couldn't register, it may be in use by another app: {}",
+                            e
+                        ));
+                    } else {
+                        bindings.insert(shortcut.id(), action);
+                        registered.push(shortcut);
+                        status.registered = true;
+                    }
+                }
+            }
+        }
+
+        if let Some(error) = &status.error {
+            logging::warn("shortcuts", &format!("Shortcut '{}' for {}: {}", accelerator, action.label(), error));
+        }
+        statuses.push(status);
+    }
+
+    {
+        let mut registry = REGISTRY.write();
+        registry.bindings = bindings;
+        registry.registered = registered;
+        registry.settings = Some(settings.clone());
+        registry.statuses = statuses.clone();
+    }
+
+    let _ = app.emit("shortcuts::status", &statuses);
+    statuses
+}
+
+/// Re-register only if `settings` differ from what is currently registered.
+/// When saves come in quick succession only the latest is applied.
+pub fn sync(app: &AppHandle, settings: &ShortcutSettings) {
+    let generation = SYNC_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let app = app.clone();
+    let settings = settings.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        let _guard = REGISTER_LOCK.lock();
+        let latest = SYNC_GENERATION.load(Ordering::SeqCst) == generation;
+        if latest && REGISTRY.read().settings.as_ref() != Some(&settings) {
+            register_locked(&app, &settings);
+        }
+    });
+}
+
+/// Statuses from the most recent registration
+pub fn statuses() -> Vec<ShortcutStatus> {
+    REGISTRY.read().statuses.clone()
+}
+
+/// Global shortcut plugin handler: dispatch a pressed shortcut to its action
+pub fn handle(app: &AppHandle, shortcut: &Shortcut, event: ShortcutEvent) {
+    if event.state != ShortcutState::Pressed {
+        return;
+    }
+
+    let action = REGISTRY.read().bindings.get(&shortcut.id()).copied();
+    match action {
+        Some(ShortcutAction::ToggleWindow) => crate::toggle_window_visibility(app),
+        Some(ShortcutAction::QuickCapture) => crate::toggle_quick_capture(app),
+        Some(ShortcutAction::OpenToday) => {
+            crate::show_window(app);
+            let _ = app.emit("navigate", "today");
+        }
+        Some(ShortcutAction::ReviewClipboardPr) => review_clipboard_pr(app),
+        Some(ShortcutAction::TaskFromClipboard) => task_from_clipboard(app),
+        None => {}
+    }
+}
+
+pub fn notify(app: &AppHandle, title: &str, body: &str) {
+    let _ = app.notification().builder().title(title).body(body).show();
+}
+
+fn review_clipboard_pr(app: &AppHandle) {
+    let url = app.clipboard().read_text().unwrap_or_default().trim().to_string();
+
+    if crate::commands::parse_pr_url(&url).is_none() {
+        notify(app, "No PR URL on clipboard", "Copy a GitHub or Graphite PR link and try again.");
+        return;
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = crate::commands::run_code_review(app.clone(), url).await {
+            notify(&app, "Code review failed", &e.to_string());
+        }
+    });
+}
+
+/// Create a task from the clipboard URL, reporting the outcome as a notification
+pub fn task_from_clipboard(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        match crate::commands::create_task_from_clipboard(app.clone()).await {
+            Ok(result) if result.created => notify(&app, "Task created", &result.title),
+            Ok(result) => notify(&app, "Task already exists", &result.title),
+            Err(e) => notify(&app, "Couldn't create task", &e.to_string()),
+        }
+    });
+}
@@ -0,0 +1,183 @@
+use crate::dates::{self, WeekStart};
+use crate::models::AppData;
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use serde::Serialize;
+
+// ============ Productivity Stats ============
+//
+// Computed only from AppData so they work offline. Tasks don't keep a
+// history of rescheduling, so carry-over and backlog figures are derived
+// from each task's current `scheduled_date`, `created_at` and `completed_at`.
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DayCount {
+    pub date: NaiveDate,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WeekCount {
+    pub week_start: NaiveDate,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BragDocCount {
+    pub doc_id: String,
+    pub title: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProductivityStats {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub tasks_completed: u32,
+    pub completed_per_day: Vec<DayCount>,
+    pub completed_per_week: Vec<WeekCount>,
+    /// Mean days from creation to completion for tasks completed in range
+    pub average_days_to_complete: Option<f64>,
+    /// Share of tasks scheduled in range (before today) that weren't
+    /// completed by the end of their scheduled day
+    pub carry_over_rate: Option<f64>,
+    /// Open (created, not yet completed) tasks at the end of each week
+    pub backlog_size: Vec<WeekCount>,
+    pub notes_per_week: Vec<WeekCount>,
+    /// Entries in each brag doc whose period overlaps the range
+    pub brag_entries_per_doc: Vec<BragDocCount>,
+}
+
+fn local_date(dt: DateTime<Utc>) -> NaiveDate {
+    dt.with_timezone(&Local).date_naive()
+}
+
+/// Week starts covering `start..=end`
+fn weeks(start: NaiveDate, end: NaiveDate, week_start: WeekStart) -> Vec<NaiveDate> {
+    dates::start_of_week(start, week_start)
+        .iter_weeks()
+        .take_while(|w| *w <= end)
+        .collect()
+}
+
+fn count_by_week(dates: &[NaiveDate], weeks: &[NaiveDate]) -> Vec<WeekCount> {
+    weeks
+        .iter()
+        .map(|week| WeekCount {
+            week_start: *week,
+            count: dates
+                .iter()
+                .filter(|d| **d >= *week && **d < *week + Duration::days(7))
+                .count() as u32,
+        })
+        .collect()
+}
+
+/// Stats for local dates `start` through `end` (inclusive), as of `today`
+pub fn compute(
+    data: &AppData,
+    start: NaiveDate,
+    end: NaiveDate,
+    today: NaiveDate,
+    week_start: WeekStart,
+) -> ProductivityStats {
+    let in_range = |date: NaiveDate| date >= start && date <= end;
+    let weeks = weeks(start, end, week_start);
+
+    let completed: Vec<_> = data
+        .tasks
+        .iter()
+        .filter(|t| t.completed)
+        .filter_map(|t| t.completed_at.map(|c| (t, c)))
+        .filter(|(_, c)| in_range(local_date(*c)))
+        .collect();
+    let completed_dates: Vec<NaiveDate> = completed.iter().map(|(_, c)| local_date(*c)).collect();
+
+    let completed_per_day = start
+        .iter_days()
+        .take_while(|d| *d <= end)
+        .map(|date| DayCount {
+            date,
+            count: completed_dates.iter().filter(|d| **d == date).count() as u32,
+        })
+        .collect();
+
+    let average_days_to_complete = (!completed.is_empty()).then(|| {
+        let total_hours: i64 = completed
+            .iter()
+            .map(|(t, c)| (*c - t.created_at).num_hours().max(0))
+            .sum();
+        total_hours as f64 / 24.0 / completed.len() as f64
+    });
+
+    let scheduled: Vec<_> = data
+        .tasks
+        .iter()
+        .filter_map(|t| t.scheduled_date.map(|d| (t, d)))
+        .filter(|(_, d)| in_range(*d) && *d < today)
+        .collect();
+    let carried_over = scheduled
+        .iter()
+        .filter(|(t, d)| match (t.completed, t.completed_at) {
+            (true, Some(c)) => local_date(c) > *d,
+            _ => true,
+        })
+        .count();
+    let carry_over_rate = (!scheduled.is_empty()).then(|| carried_over as f64 / scheduled.len() as f64);
+
+    let backlog_size = weeks
+        .iter()
+        .map(|week| {
+            let week_end = (*week + Duration::days(6)).min(today);
+            let count = data
+                .tasks
+                .iter()
+                .filter(|t| local_date(t.created_at) <= week_end)
+                .filter(|t| match (t.completed, t.completed_at) {
+                    (true, Some(c)) => local_date(c) > week_end,
+                    (true, None) => false,
+                    (false, _) => true,
+                })
+                .count();
+            WeekCount {
+                week_start: *week,
+                count: count as u32,
+            }
+        })
+        .collect();
+
+    let note_dates: Vec<NaiveDate> = data
+        .notes
+        .iter()
+        .map(|n| local_date(n.created_at))
+        .filter(|d| in_range(*d))
+        .collect();
+
+    let mut brag_entries_per_doc: Vec<BragDocCount> = data
+        .brag_docs
+        .iter()
+        .filter(|doc| doc.start_date <= end && doc.end_date >= start)
+        .map(|doc| BragDocCount {
+            doc_id: doc.id.clone(),
+            title: doc.title.clone(),
+            start_date: doc.start_date,
+            end_date: doc.end_date,
+            count: doc.entries.len() as u32,
+        })
+        .collect();
+    brag_entries_per_doc.sort_by_key(|d| d.start_date);
+
+    ProductivityStats {
+        start,
+        end,
+        tasks_completed: completed.len() as u32,
+        completed_per_day,
+        completed_per_week: count_by_week(&completed_dates, &weeks),
+        average_days_to_complete,
+        carry_over_rate,
+        backlog_size,
+        notes_per_week: count_by_week(&note_dates, &weeks),
+        brag_entries_per_doc,
+    }
+}
@@ -1,13 +1,15 @@
+use crate::backup;
+use crate::error::AppError;
+use crate::logging;
 use crate::models::AppData;
-use chrono::{Duration, Local};
+use std::collections::BTreeSet;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 const APP_DIR_NAME: &str = "atulify";
 const DATA_FILE: &str = "data.json";
 const IMAGES_DIR: &str = "images";
 const BACKUPS_DIR: &str = "backups";
-const BACKUP_RETENTION_DAYS: i64 = 7;
 
 pub fn get_app_dir() -> PathBuf {
     let app_support = dirs::data_dir().expect("Could not find app support directory");
@@ -26,19 +28,20 @@ pub fn get_backups_dir() -> PathBuf {
     get_app_dir().join(BACKUPS_DIR)
 }
 
-pub fn ensure_directories() -> Result<(), String> {
+pub fn ensure_directories() -> Result<(), AppError> {
     let app_dir = get_app_dir();
     let images_dir = get_images_dir();
     let backups_dir = get_backups_dir();
 
-    fs::create_dir_all(&app_dir).map_err(|e| e.to_string())?;
-    fs::create_dir_all(&images_dir).map_err(|e| e.to_string())?;
-    fs::create_dir_all(&backups_dir).map_err(|e| e.to_string())?;
+    for dir in [&app_dir, &images_dir, &backups_dir] {
+        fs::create_dir_all(dir)
+            .map_err(|e| AppError::Storage(format!("Failed to create {}: {}", dir.display(), e)))?;
+    }
 
     Ok(())
 }
 
-pub fn load_data() -> Result<AppData, String> {
+pub fn load_data() -> Result<AppData, AppError> {
     ensure_directories()?;
 
     let data_path = get_data_path();
@@ -49,142 +52,89 @@ pub fn load_data() -> Result<AppData, String> {
         return Ok(default_data);
     }
 
-    let contents = fs::read_to_string(&data_path).map_err(|e| e.to_string())?;
+    let contents = fs::read_to_string(&data_path)
+        .map_err(|e| AppError::Storage(format!("Failed to read data file: {}", e)))?;
 
     match serde_json::from_str(&contents) {
         Ok(data) => Ok(data),
         Err(e) => {
             // Try to recover from backup
-            if let Ok(backup_data) = restore_latest_backup() {
-                eprintln!("Data file corrupted, restored from backup: {}", e);
+            if let Ok(backup_data) = backup::restore_latest() {
+                logging::error("storage", &format!("Data file corrupted, restored from backup: {}", e));
                 Ok(backup_data)
             } else {
-                Err(format!("Failed to parse data file and no backup available: {}", e))
+                Err(AppError::DataCorrupt(e.to_string()))
             }
         }
     }
 }
 
-pub fn save_data(data: &AppData) -> Result<(), String> {
+pub fn save_data(data: &AppData) -> Result<(), AppError> {
     ensure_directories()?;
 
     let data_path = get_data_path();
-    let contents = serde_json::to_string_pretty(data).map_err(|e| e.to_string())?;
+    let contents = serde_json::to_string_pretty(data).map_err(|e| AppError::Internal(e.to_string()))?;
 
     // Atomic write: write to temp file first, then rename
     // This prevents data corruption if the app crashes mid-write
     let temp_path = data_path.with_extension("json.tmp");
 
-    fs::write(&temp_path, &contents).map_err(|e| format!("Failed to write temp file: {}", e))?;
+    fs::write(&temp_path, &contents).map_err(|e| AppError::Storage(format!("Failed to write temp file: {}", e)))?;
 
-    fs::rename(&temp_path, &data_path).map_err(|e| format!("Failed to rename temp file: {}", e))?;
+    fs::rename(&temp_path, &data_path).map_err(|e| AppError::Storage(format!("Failed to rename temp file: {}", e)))?;
 
     Ok(())
 }
 
-pub fn create_backup() -> Result<String, String> {
-    ensure_directories()?;
-
-    let data_path = get_data_path();
-    if !data_path.exists() {
-        return Err("No data file to backup".to_string());
-    }
-
-    let backups_dir = get_backups_dir();
-    let date = Local::now().format("%Y-%m-%d").to_string();
-    let backup_name = format!("data-{}.json", date);
-    let backup_path = backups_dir.join(&backup_name);
-
-    fs::copy(&data_path, &backup_path).map_err(|e| e.to_string())?;
-
-    // Clean up old backups
-    cleanup_old_backups()?;
-
-    Ok(backup_name)
+/// File name within the images dir for an image reference, which is
+/// stored as a full path
+pub fn image_file_name(reference: &str) -> Option<String> {
+    Some(Path::new(reference).file_name()?.to_str()?.to_string())
 }
 
-pub fn cleanup_old_backups() -> Result<(), String> {
-    let backups_dir = get_backups_dir();
-    let cutoff = Local::now() - Duration::days(BACKUP_RETENTION_DAYS);
-
-    if let Ok(entries) = fs::read_dir(&backups_dir) {
-        for entry in entries.flatten() {
-            if let Ok(metadata) = entry.metadata() {
-                if let Ok(modified) = metadata.modified() {
-                    let modified_time: chrono::DateTime<Local> = modified.into();
-                    if modified_time < cutoff {
-                        let _ = fs::remove_file(entry.path());
-                    }
-                }
-            }
-        }
-    }
-
-    Ok(())
+/// File names (within the images dir) of every image a note or brag entry
+/// refers to, trashed ones included
+pub fn referenced_images(data: &AppData) -> BTreeSet<String> {
+    let note_images = data.notes.iter().flat_map(|n| &n.images);
+    let entry_images = data.brag_docs.iter().flat_map(|d| &d.entries).flat_map(|e| &e.images);
+    note_images
+        .chain(entry_images)
+        .filter_map(|reference| image_file_name(reference))
+        .collect()
 }
 
-pub fn get_backups() -> Result<Vec<String>, String> {
-    let backups_dir = get_backups_dir();
-    let mut backups = Vec::new();
-
-    if let Ok(entries) = fs::read_dir(&backups_dir) {
-        for entry in entries.flatten() {
-            if let Some(name) = entry.file_name().to_str() {
-                if name.starts_with("data-") && name.ends_with(".json") {
-                    backups.push(name.to_string());
-                }
-            }
-        }
-    }
-
-    backups.sort_by(|a, b| b.cmp(a)); // Sort newest first
-    Ok(backups)
-}
-
-pub fn restore_backup(backup_name: &str) -> Result<AppData, String> {
-    let backups_dir = get_backups_dir();
-    let backup_path = backups_dir.join(backup_name);
-
-    if !backup_path.exists() {
-        return Err(format!("Backup '{}' not found", backup_name));
-    }
-
-    let contents = fs::read_to_string(&backup_path).map_err(|e| e.to_string())?;
-    let data: AppData = serde_json::from_str(&contents).map_err(|e| e.to_string())?;
-
-    // Save restored data as current
-    save_data(&data)?;
-
-    Ok(data)
+/// A bare file name that stays inside the directory it's joined onto: no
+/// separators, no `..`, and not hidden
+pub fn is_plain_file_name(name: &str) -> bool {
+    !name.is_empty() && !name.starts_with('.') && Path::new(name).file_name().and_then(|n| n.to_str()) == Some(name)
 }
 
-fn restore_latest_backup() -> Result<AppData, String> {
-    let backups = get_backups()?;
-
-    if let Some(latest) = backups.first() {
-        restore_backup(latest)
-    } else {
-        Err("No backups available".to_string())
+fn image_path(filename: &str) -> Result<PathBuf, AppError> {
+    if !is_plain_file_name(filename) {
+        return Err(AppError::InvalidInput(format!("Invalid image name '{}'", filename)));
     }
+    Ok(get_images_dir().join(filename))
 }
 
-pub fn save_image(filename: &str, data: &[u8]) -> Result<String, String> {
+/// Write an image into the images dir, returning its full path. Written to
+/// a temp file first, so a half-written image never has the real name.
+pub fn save_image(filename: &str, data: &[u8]) -> Result<String, AppError> {
     ensure_directories()?;
 
-    let images_dir = get_images_dir();
-    let image_path = images_dir.join(filename);
+    let image_path = image_path(filename)?;
+    let temp_path = get_images_dir().join(format!(".{}.tmp", filename));
 
-    fs::write(&image_path, data).map_err(|e| e.to_string())?;
+    fs::write(&temp_path, data).map_err(|e| AppError::Storage(format!("Failed to save image: {}", e)))?;
+    fs::rename(&temp_path, &image_path).map_err(|e| AppError::Storage(format!("Failed to save image: {}", e)))?;
 
     Ok(image_path.to_string_lossy().to_string())
 }
 
-pub fn delete_image(filename: &str) -> Result<(), String> {
-    let images_dir = get_images_dir();
-    let image_path = images_dir.join(filename);
+pub fn delete_image(filename: &str) -> Result<(), AppError> {
+    let image_path = image_path(filename)?;
 
     if image_path.exists() {
-        fs::remove_file(&image_path).map_err(|e| e.to_string())?;
+        fs::remove_file(&image_path).map_err(|e| AppError::Storage(format!("Failed to delete image: {}", e)))?;
     }
 
     Ok(())
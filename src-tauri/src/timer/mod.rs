@@ -0,0 +1,272 @@
+use crate::error::AppError;
+use crate::history;
+use crate::logging;
+use crate::models::{AppData, TimeInterval};
+use crate::storage;
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::collections::HashMap;
+use tauri::{AppHandle, Emitter};
+
+// ============ Task Timers ============
+//
+// At most one timer runs at a time. A running timer is an interval on the
+// task with no `ended_at`; stopping it fills that in.
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunningTimer {
+    pub task_id: String,
+    pub title: String,
+    pub started_at: DateTime<Utc>,
+}
+
+pub fn running(data: &AppData) -> Option<RunningTimer> {
+    data.tasks.iter().find_map(|task| {
+        task.time_intervals
+            .iter()
+            .find(|i| i.ended_at.is_none())
+            .map(|i| RunningTimer {
+                task_id: task.id.clone(),
+                title: task.title.clone(),
+                started_at: i.started_at,
+            })
+    })
+}
+
+/// Start a timer on a task. Fails if any timer is already running.
+pub fn start(data: &mut AppData, task_id: &str, now: DateTime<Utc>) -> Result<RunningTimer, AppError> {
+    if let Some(timer) = running(data) {
+        return Err(AppError::InvalidInput(if timer.task_id == task_id {
+            "Timer is already running for this task".to_string()
+        } else {
+            format!("A timer is already running for \"{}\"", timer.title)
+        }));
+    }
+
+    let task = data
+        .tasks
+        .iter_mut()
+        .find(|t| t.id == task_id && t.deleted_at.is_none())
+        .ok_or_else(|| AppError::NotFound(format!("Task '{}' not found", task_id)))?;
+    if task.completed || task.archived {
+        return Err(AppError::InvalidInput("Can't start a timer on a finished task".to_string()));
+    }
+
+    task.time_intervals.push(TimeInterval {
+        started_at: now,
+        ended_at: None,
+    });
+    Ok(RunningTimer {
+        task_id: task.id.clone(),
+        title: task.title.clone(),
+        started_at: now,
+    })
+}
+
+/// Stop a task's running timer, returning the length of the closed interval in seconds
+pub fn stop(data: &mut AppData, task_id: &str, now: DateTime<Utc>) -> Result<i64, AppError> {
+    let task = data
+        .tasks
+        .iter_mut()
+        .find(|t| t.id == task_id)
+        .ok_or_else(|| AppError::NotFound(format!("Task '{}' not found", task_id)))?;
+    let interval = task
+        .time_intervals
+        .iter_mut()
+        .find(|i| i.ended_at.is_none())
+        .ok_or_else(|| AppError::InvalidInput("No timer is running for this task".to_string()))?;
+
+    let ended_at = now.max(interval.started_at);
+    interval.ended_at = Some(ended_at);
+    Ok((ended_at - interval.started_at).num_seconds())
+}
+
+/// Stop every running timer, returning the ids of the affected tasks
+pub fn stop_all(data: &mut AppData, now: DateTime<Utc>) -> Vec<String> {
+    let mut stopped = Vec::new();
+    for task in &mut data.tasks {
+        for interval in task.time_intervals.iter_mut().filter(|i| i.ended_at.is_none()) {
+            interval.ended_at = Some(now.max(interval.started_at));
+            stopped.push(task.id.clone());
+        }
+    }
+    stopped
+}
+
+/// Stop timers left running on tasks that have been completed, archived or trashed
+pub fn stop_finished(data: &mut AppData, now: DateTime<Utc>) {
+    for task in data.tasks.iter_mut().filter(|t| t.completed || t.archived || t.deleted_at.is_some()) {
+        let completed_at = task.completed_at;
+        for interval in task.time_intervals.iter_mut().filter(|i| i.ended_at.is_none()) {
+            // End at completion when known, so time after finishing isn't counted
+            let ended_at = completed_at.filter(|c| *c <= now).unwrap_or(now);
+            interval.ended_at = Some(ended_at.max(interval.started_at));
+        }
+    }
+}
+
+// ============ Sleep Handling ============
+
+/// Task whose timer was stopped when the system went to sleep
+static PAUSED_FOR_SLEEP: Lazy<Mutex<Option<String>>> = Lazy::new(|| Mutex::new(None));
+
+/// Stop the running timer before sleep so the time asleep isn't counted
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+pub fn pause_for_sleep(app: &AppHandle) {
+    let Ok(mut data) = storage::load_data() else {
+        return;
+    };
+    let before = data.clone();
+    let stopped = stop_all(&mut data, Utc::now());
+    if stopped.is_empty() {
+        return;
+    }
+
+    match storage::save_data(&data) {
+        Ok(()) => {
+            history::record(app, Some("Pause timer for sleep"), &before, &data);
+            *PAUSED_FOR_SLEEP.lock() = stopped.into_iter().next();
+            let _ = app.emit("data::changed", ());
+        }
+        Err(e) => logging::error("timer", &format!("Failed to pause timer for sleep: {}", e)),
+    }
+}
+
+/// Restart the timer stopped by `pause_for_sleep`, if any
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+pub fn resume_after_wake(app: &AppHandle) {
+    let Some(task_id) = PAUSED_FOR_SLEEP.lock().take() else {
+        return;
+    };
+    let Ok(mut data) = storage::load_data() else {
+        return;
+    };
+    let before = data.clone();
+
+    // The task may have been finished, or another timer started, meanwhile
+    if start(&mut data, &task_id, Utc::now()).is_ok() {
+        match storage::save_data(&data) {
+            Ok(()) => {
+                history::record(app, Some("Resume timer after sleep"), &before, &data);
+                let _ = app.emit("data::changed", ());
+            }
+            Err(e) => logging::error("timer", &format!("Failed to resume timer after wake: {}", e)),
+        }
+    }
+}
+
+// ============ Time Totals ============
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TagTotal {
+    /// None for time on untagged tasks
+    pub tag_id: Option<String>,
+    pub name: String,
+    pub seconds: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DayTotal {
+    pub date: NaiveDate,
+    pub seconds: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TimeTotals {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub total_seconds: i64,
+    /// Most time first; a task with several tags counts toward each of them
+    pub by_tag: Vec<TagTotal>,
+    /// Every day from `start` to `end`, including days with no time
+    pub by_day: Vec<DayTotal>,
+}
+
+fn local_midnight(date: NaiveDate) -> DateTime<Utc> {
+    let naive = date.and_hms_opt(0, 0, 0).unwrap_or_default();
+    naive
+        .and_local_timezone(Local)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+        .unwrap_or_else(|| naive.and_utc())
+}
+
+/// Time tracked between local dates `start` and `end` (inclusive). Intervals
+/// are clipped to the range and split at local midnight; running timers
+/// count up to `now`.
+pub fn totals(data: &AppData, start: NaiveDate, end: NaiveDate, now: DateTime<Utc>) -> TimeTotals {
+    let range_start = local_midnight(start);
+    let range_end = local_midnight(end + Duration::days(1));
+
+    let mut by_day: Vec<DayTotal> = start
+        .iter_days()
+        .take_while(|d| *d <= end)
+        .map(|date| DayTotal { date, seconds: 0 })
+        .collect();
+    let mut by_tag: HashMap<Option<String>, i64> = HashMap::new();
+    let mut total_seconds = 0;
+
+    for task in &data.tasks {
+        let mut task_seconds = 0;
+
+        for interval in &task.time_intervals {
+            let mut cursor = interval.started_at.max(range_start);
+            let stop = interval.ended_at.unwrap_or(now).min(range_end);
+
+            while cursor < stop {
+                let day = cursor.with_timezone(&Local).date_naive();
+                let next = local_midnight(day + Duration::days(1)).min(stop);
+                if next <= cursor {
+                    break;
+                }
+                let seconds = (next - cursor).num_seconds();
+                if let Some(total) = by_day.iter_mut().find(|d| d.date == day) {
+                    total.seconds += seconds;
+                }
+                task_seconds += seconds;
+                cursor = next;
+            }
+        }
+
+        if task_seconds == 0 {
+            continue;
+        }
+        total_seconds += task_seconds;
+
+        let known_tags: Vec<&String> = task
+            .tag_ids
+            .iter()
+            .filter(|id| data.tags.iter().any(|t| &t.id == *id))
+            .collect();
+        if known_tags.is_empty() {
+            *by_tag.entry(None).or_default() += task_seconds;
+        }
+        for tag_id in known_tags {
+            *by_tag.entry(Some(tag_id.clone())).or_default() += task_seconds;
+        }
+    }
+
+    let mut by_tag: Vec<TagTotal> = by_tag
+        .into_iter()
+        .map(|(tag_id, seconds)| TagTotal {
+            name: tag_id
+                .as_ref()
+                .and_then(|id| data.tags.iter().find(|t| &t.id == id))
+                .map(|t| t.name.clone())
+                .unwrap_or_else(|| "Untagged".to_string()),
+            tag_id,
+            seconds,
+        })
+        .collect();
+    by_tag.sort_by(|a, b| b.seconds.cmp(&a.seconds).then_with(|| a.name.cmp(&b.name)));
+
+    TimeTotals {
+        start,
+        end,
+        total_seconds,
+        by_tag,
+        by_day,
+    }
+}
@@ -0,0 +1,228 @@
+use crate::backup::note_title;
+use crate::error::AppError;
+use crate::history;
+use crate::logging;
+use crate::models::{AppData, TrashSettings};
+use crate::storage;
+use crate::timer;
+use chrono::{DateTime, Duration, Utc};
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+// ============ Trash ============
+//
+// Deleting a task, note, brag entry or reminder sets its `deleted_at`
+// instead of removing it. Trashed items are hidden from views, stats and
+// exports, can be restored until they're purged, and keep their images on
+// disk until then.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrashKind {
+    Task,
+    Note,
+    BragEntry,
+    Notification,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TrashItem {
+    pub kind: TrashKind,
+    pub id: String,
+    pub title: String,
+    /// The brag doc an entry belongs to
+    pub context: Option<String>,
+    pub deleted_at: DateTime<Utc>,
+    /// `None` when automatic purging is off
+    pub purge_at: Option<DateTime<Utc>>,
+}
+
+static POLICY: Lazy<RwLock<TrashSettings>> = Lazy::new(|| RwLock::new(TrashSettings::default()));
+
+pub fn configure(settings: &TrashSettings) {
+    *POLICY.write() = settings.clone();
+}
+
+pub fn policy() -> TrashSettings {
+    POLICY.read().clone()
+}
+
+/// Where an item's `deleted_at` lives, if it exists
+fn deleted_at_mut<'a>(data: &'a mut AppData, kind: TrashKind, id: &str) -> Option<&'a mut Option<DateTime<Utc>>> {
+    match kind {
+        TrashKind::Task => data.tasks.iter_mut().find(|t| t.id == id).map(|t| &mut t.deleted_at),
+        TrashKind::Note => data.notes.iter_mut().find(|n| n.id == id).map(|n| &mut n.deleted_at),
+        TrashKind::BragEntry => data
+            .brag_docs
+            .iter_mut()
+            .flat_map(|d| d.entries.iter_mut())
+            .find(|e| e.id == id)
+            .map(|e| &mut e.deleted_at),
+        TrashKind::Notification => data
+            .notifications
+            .iter_mut()
+            .find(|n| n.id == id)
+            .map(|n| &mut n.deleted_at),
+    }
+}
+
+fn not_found(kind: TrashKind, id: &str) -> AppError {
+    AppError::NotFound(format!("{:?} '{}' not found", kind, id))
+}
+
+/// Move an item to the trash; trashing it again keeps the original time
+pub fn trash(data: &mut AppData, kind: TrashKind, id: &str, now: DateTime<Utc>) -> Result<(), AppError> {
+    let deleted_at = deleted_at_mut(data, kind, id).ok_or_else(|| not_found(kind, id))?;
+    deleted_at.get_or_insert(now);
+    if kind == TrashKind::Task {
+        timer::stop_finished(data, now);
+    }
+    Ok(())
+}
+
+pub fn restore(data: &mut AppData, kind: TrashKind, id: &str) -> Result<(), AppError> {
+    match deleted_at_mut(data, kind, id) {
+        Some(deleted_at) if deleted_at.is_some() => {
+            *deleted_at = None;
+            Ok(())
+        }
+        _ => Err(AppError::NotFound(format!("{:?} '{}' is not in the trash", kind, id))),
+    }
+}
+
+fn purge_at(deleted_at: DateTime<Utc>, retention_days: u32) -> Option<DateTime<Utc>> {
+    (retention_days > 0).then(|| deleted_at + Duration::days(retention_days as i64))
+}
+
+/// Everything in the trash, most recently deleted first
+pub fn list(data: &AppData, retention_days: u32) -> Vec<TrashItem> {
+    let item = |kind, id: &str, title: String, context: Option<String>, deleted_at| TrashItem {
+        kind,
+        id: id.to_string(),
+        title,
+        context,
+        deleted_at,
+        purge_at: purge_at(deleted_at, retention_days),
+    };
+
+    let tasks = data
+        .tasks
+        .iter()
+        .filter_map(|t| Some(item(TrashKind::Task, &t.id, t.title.clone(), None, t.deleted_at?)));
+    let notes = data
+        .notes
+        .iter()
+        .filter_map(|n| Some(item(TrashKind::Note, &n.id, note_title(&n.content), None, n.deleted_at?)));
+    let entries = data.brag_docs.iter().flat_map(|d| {
+        d.entries.iter().filter_map(move |e| {
+            Some(item(TrashKind::BragEntry, &e.id, e.title.clone(), Some(d.title.clone()), e.deleted_at?))
+        })
+    });
+    let notifications = data
+        .notifications
+        .iter()
+        .filter_map(|n| Some(item(TrashKind::Notification, &n.id, n.title.clone(), None, n.deleted_at?)));
+
+    let mut items: Vec<TrashItem> = tasks.chain(notes).chain(entries).chain(notifications).collect();
+    items.sort_by_key(|i| std::cmp::Reverse(i.deleted_at));
+    items
+}
+
+/// A copy of `data` without trashed items, for stats, reviews and exports
+pub fn visible(data: &AppData) -> AppData {
+    let mut data = data.clone();
+    data.tasks.retain(|t| t.deleted_at.is_none());
+    data.notes.retain(|n| n.deleted_at.is_none());
+    for doc in &mut data.brag_docs {
+        doc.entries.retain(|e| e.deleted_at.is_none());
+    }
+    data.notifications.retain(|n| n.deleted_at.is_none());
+    data
+}
+
+// ============ Purging ============
+
+/// Remove trashed items matching `purge`, returning how many were removed
+/// and the image files no remaining item refers to
+fn remove_where(data: &mut AppData, purge: impl Fn(TrashKind, &str, DateTime<Utc>) -> bool) -> (usize, Vec<String>) {
+    let images_before = storage::referenced_images(data);
+    let keep = |kind, id: &str, deleted_at: Option<DateTime<Utc>>| !deleted_at.is_some_and(|d| purge(kind, id, d));
+
+    let count_before = list(data, 0).len();
+    data.tasks.retain(|t| keep(TrashKind::Task, &t.id, t.deleted_at));
+    data.notes.retain(|n| keep(TrashKind::Note, &n.id, n.deleted_at));
+    for doc in &mut data.brag_docs {
+        doc.entries.retain(|e| keep(TrashKind::BragEntry, &e.id, e.deleted_at));
+    }
+    data.notifications.retain(|n| keep(TrashKind::Notification, &n.id, n.deleted_at));
+    let removed = count_before - list(data, 0).len();
+
+    let images_after = storage::referenced_images(data);
+    (removed, images_before.difference(&images_after).cloned().collect())
+}
+
+/// Remove items trashed more than `retention_days` ago; 0 keeps them forever
+pub fn purge_expired(data: &mut AppData, now: DateTime<Utc>, retention_days: u32) -> (usize, Vec<String>) {
+    if retention_days == 0 {
+        return (0, Vec::new());
+    }
+    remove_where(data, |_, _, deleted_at| purge_at(deleted_at, retention_days).is_some_and(|p| p <= now))
+}
+
+/// Remove one trashed item for good
+pub fn purge_item(data: &mut AppData, kind: TrashKind, id: &str) -> Result<Vec<String>, AppError> {
+    match remove_where(data, |k, i, _| k == kind && i == id) {
+        (0, _) => Err(AppError::NotFound(format!("{:?} '{}' is not in the trash", kind, id))),
+        (_, images) => Ok(images),
+    }
+}
+
+/// Remove everything in the trash, returning how many items were removed
+pub fn empty(data: &mut AppData) -> (usize, Vec<String>) {
+    remove_where(data, |_, _, _| true)
+}
+
+/// Delete image files left over from purged items. Images an undo step
+/// could bring back are kept; the unused image cleanup removes them once
+/// the step is gone. Backups keep their own copies, so a restore still
+/// brings them back.
+pub fn delete_images(images: &[String]) {
+    let in_history = history::referenced_images();
+    for image in images.iter().filter(|image| !in_history.contains(*image)) {
+        if let Err(e) = storage::delete_image(image) {
+            logging::warn("trash", &format!("Failed to delete image '{}': {}", image, e));
+        }
+    }
+}
+
+// ============ Scheduled Purge ============
+
+const PURGE_INTERVAL_SECS: u64 = 60 * 60;
+
+fn run_purge(app: &AppHandle) -> Result<(), AppError> {
+    let mut data = storage::load_data()?;
+    let before = data.clone();
+    let (removed, images) = purge_expired(&mut data, Utc::now(), policy().retention_days);
+    if removed == 0 {
+        return Ok(());
+    }
+
+    storage::save_data(&data)?;
+    history::record(app, Some("Purge expired trash"), &before, &data);
+    delete_images(&images);
+    logging::info("trash", &format!("Purged {} expired items and {} images", removed, images.len()));
+    let _ = app.emit("data::changed", ());
+    Ok(())
+}
+
+/// Purge expired items now, then check again every hour
+pub fn start_scheduler(app: AppHandle) {
+    std::thread::spawn(move || loop {
+        if let Err(e) = run_purge(&app) {
+            logging::error("trash", &format!("Scheduled purge failed: {}", e));
+        }
+        std::thread::sleep(std::time::Duration::from_secs(PURGE_INTERVAL_SECS));
+    });
+}
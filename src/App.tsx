@@ -1,5 +1,6 @@
 import { useState, useEffect, useCallback } from 'react';
 import { exit } from '@tauri-apps/plugin-process';
+import { message } from '@tauri-apps/plugin-dialog';
 import { getCurrentWindow } from '@tauri-apps/api/window';
 import { listen } from '@tauri-apps/api/event';
 import { Navigation, DropdownMenu, Onboarding } from './components';
@@ -14,16 +15,26 @@ import {
   NotificationsView,
   SettingsView,
 } from './views';
-import { useAppData } from './hooks/useAppData';
+import { getHistoryStatus, redo, undo, useAppData } from './hooks/useAppData';
 import { useNotifications } from './hooks/useNotifications';
 import { usePrData } from './hooks/usePrData';
-import type { ViewType, Theme, AppData, Settings } from './types';
+import type { ViewType, Theme, AppData, HistoryStatus, Settings, StepResult } from './types';
 import './styles/global.css';
 
+// Undo and redo leave items edited again since the step alone; say which
+async function reportSkipped(result: StepResult) {
+  if (result.skipped.length === 0) return;
+  await message(`These were changed since and were left as they are:\n${result.skipped.join('\n')}`, {
+    title: 'Some changes were skipped',
+    kind: 'warning',
+  });
+}
+
 function App() {
   const { data, loading, error, saveData, loadData } = useAppData();
   const [activeView, setActiveView] = useState<ViewType>('today');
   const [menuOpen, setMenuOpen] = useState(false);
+  const [history, setHistory] = useState<HistoryStatus>({ undo_label: null, redo_label: null });
   const prData = usePrData();
 
   // Initialize notification scheduling
@@ -44,6 +55,43 @@ function App() {
     };
   }, [loadData]);
 
+  // Reload when data is written outside this window (e.g. quick capture)
+  useEffect(() => {
+    const unlisten = listen('data::changed', () => {
+      loadData();
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [loadData]);
+
+  // Undo history lives in the backend, so it survives hiding the window
+  useEffect(() => {
+    getHistoryStatus()
+      .then(setHistory)
+      .catch((e) => console.error('Failed to load undo history:', e));
+
+    const unlisten = listen<HistoryStatus>('history::changed', (event) => {
+      setHistory(event.payload);
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // Global shortcuts can ask for a specific view
+  useEffect(() => {
+    const unlisten = listen<ViewType>('navigate', (event) => {
+      setActiveView(event.payload);
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
   // Apply theme to document
   useEffect(() => {
     document.documentElement.setAttribute('data-theme', data.settings.theme);
@@ -83,6 +131,23 @@ function App() {
     [saveData]
   );
 
+  // The backend emits data::changed, which reloads the data
+  const handleUndo = useCallback(async () => {
+    try {
+      await reportSkipped(await undo());
+    } catch (e) {
+      console.error('Failed to undo:', e);
+    }
+  }, []);
+
+  const handleRedo = useCallback(async () => {
+    try {
+      await reportSkipped(await redo());
+    } catch (e) {
+      console.error('Failed to redo:', e);
+    }
+  }, []);
+
   const handleQuit = useCallback(async () => {
     await exit(0);
   }, []);
@@ -114,11 +179,24 @@ function App() {
         const window = getCurrentWindow();
         await window.hide();
       }
+
+      // Cmd+Z / Cmd+Shift+Z undo and redo data changes, except while
+      // typing, where they undo text edits
+      const target = event.target as HTMLElement;
+      const typing = target.isContentEditable || ['INPUT', 'TEXTAREA', 'SELECT'].includes(target.tagName);
+      if ((event.metaKey || event.ctrlKey) && event.key.toLowerCase() === 'z' && !typing) {
+        event.preventDefault();
+        if (event.shiftKey) {
+          await handleRedo();
+        } else {
+          await handleUndo();
+        }
+      }
     }
 
     document.addEventListener('keydown', handleKeyDown);
     return () => document.removeEventListener('keydown', handleKeyDown);
-  }, [menuOpen]);
+  }, [menuOpen, handleUndo, handleRedo]);
 
   if (loading) {
     return (
@@ -190,6 +268,10 @@ function App() {
         darkMode={data.settings.dark_mode}
         onThemeChange={handleThemeChange}
         onDarkModeToggle={handleDarkModeToggle}
+        undoLabel={history.undo_label}
+        redoLabel={history.redo_label}
+        onUndo={handleUndo}
+        onRedo={handleRedo}
         onQuit={handleQuit}
       />
     </div>
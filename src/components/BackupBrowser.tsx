@@ -0,0 +1,347 @@
+import { useEffect, useState } from 'react';
+import { Button } from './Button';
+import { Modal } from './Modal';
+import {
+  errorMessage,
+  getBackupItems,
+  getBackups,
+  previewBackup,
+  restoreBackup,
+  restoreBackupItems,
+} from '../hooks/useAppData';
+import type {
+  BackupItem,
+  BackupItemKind,
+  BackupPreview,
+  CollectionDiff,
+  ConflictStrategy,
+  ItemCounts,
+} from '../types';
+import './BackupBrowser.css';
+
+const COUNT_ROWS: { key: keyof ItemCounts; label: string }[] = [
+  { key: 'tasks', label: 'Tasks' },
+  { key: 'notes', label: 'Notes' },
+  { key: 'brag_docs', label: 'Brag Docs' },
+  { key: 'brag_entries', label: 'Brag Entries' },
+  { key: 'tags', label: 'Tags' },
+  { key: 'notifications', label: 'Reminders' },
+  { key: 'focus_sessions', label: 'Focus Sessions' },
+];
+
+const DIFF_SECTIONS: { key: 'tasks' | 'notes' | 'brag_docs' | 'brag_entries'; label: string }[] = [
+  { key: 'tasks', label: 'Tasks' },
+  { key: 'notes', label: 'Notes' },
+  { key: 'brag_docs', label: 'Brag Docs' },
+  { key: 'brag_entries', label: 'Brag Entries' },
+];
+
+const ITEM_KINDS: { kind: BackupItemKind; label: string }[] = [
+  { kind: 'task', label: 'Tasks' },
+  { kind: 'note', label: 'Notes' },
+  { kind: 'brag_doc', label: 'Brag Docs' },
+  { kind: 'brag_entry', label: 'Brag Entries' },
+];
+
+const STATUS_LABELS: Record<BackupItem['status'], string> = {
+  missing: 'Missing',
+  same: 'Unchanged',
+  different: 'Changed',
+};
+
+// "data-2026-10-18T14-05-09-pre-restore.tar.gz" -> "2026-10-18 14:05 (pre-restore)"
+function backupLabel(name: string): string {
+  const match = name.match(/^data-(\d{4}-\d{2}-\d{2})(?:T(\d{2})-(\d{2})-\d{2})?(?:-([a-z0-9-]+))?\./);
+  if (!match) return name;
+  const [, date, hour, minute, reason] = match;
+  const time = hour ? ` ${hour}:${minute}` : '';
+  return `${date}${time}${reason ? ` (${reason})` : ''}`;
+}
+
+function DiffList({ label, diff }: { label: string; diff: CollectionDiff }) {
+  if (diff.added.length + diff.removed.length + diff.changed.length === 0) return null;
+  return (
+    <div className="backup-diff-section">
+      <h4 className="backup-diff-title">{label}</h4>
+      <ul className="backup-diff-list">
+        {diff.added.map((item) => (
+          <li key={item.id} className="backup-diff-added">
+            + {item.title}
+          </li>
+        ))}
+        {diff.removed.map((item) => (
+          <li key={item.id} className="backup-diff-removed">
+            − {item.title}
+          </li>
+        ))}
+        {diff.changed.map((item) => (
+          <li key={item.id} className="backup-diff-changed">
+            ~ {item.title} <span className="backup-diff-fields">{item.fields.join(', ')}</span>
+          </li>
+        ))}
+      </ul>
+    </div>
+  );
+}
+
+interface BackupBrowserProps {
+  isOpen: boolean;
+  onClose: () => void;
+}
+
+/**
+ * Pick a backup, see what restoring it would change, then restore all of
+ * it or just selected items. The current data is snapshotted first, so a
+ * restore can be undone.
+ */
+export function BackupBrowser({ isOpen, onClose }: BackupBrowserProps) {
+  const [backups, setBackups] = useState<string[]>([]);
+  const [selected, setSelected] = useState<string | null>(null);
+  const [preview, setPreview] = useState<BackupPreview | null>(null);
+  const [loading, setLoading] = useState(false);
+  const [confirming, setConfirming] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const [mode, setMode] = useState<'changes' | 'items'>('changes');
+  const [itemKind, setItemKind] = useState<BackupItemKind>('note');
+  const [items, setItems] = useState<BackupItem[]>([]);
+  const [checked, setChecked] = useState<Set<string>>(new Set());
+  const [onConflict, setOnConflict] = useState<ConflictStrategy>('keep_both');
+  const [message, setMessage] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!isOpen) return;
+    setSelected(null);
+    setPreview(null);
+    setError(null);
+    setMessage(null);
+    getBackups()
+      .then(setBackups)
+      .catch((err) => setError(errorMessage(err)));
+  }, [isOpen]);
+
+  useEffect(() => {
+    if (!selected || mode !== 'items') return;
+    setChecked(new Set());
+    getBackupItems(selected, itemKind)
+      .then(setItems)
+      .catch((err) => setError(errorMessage(err)));
+  }, [selected, mode, itemKind]);
+
+  const toggleItem = (id: string) => {
+    setChecked((prev) => {
+      const next = new Set(prev);
+      if (next.has(id)) {
+        next.delete(id);
+      } else {
+        next.add(id);
+      }
+      return next;
+    });
+  };
+
+  const handleRestoreItems = async () => {
+    if (!selected || checked.size === 0) return;
+    setLoading(true);
+    setError(null);
+    try {
+      const restored = await restoreBackupItems(
+        selected,
+        [...checked].map((id) => ({ kind: itemKind, id })),
+        onConflict
+      );
+      const count = (outcome: string) => restored.filter((r) => r.outcome === outcome).length;
+      setMessage(
+        `Restored ${restored.length - count('unchanged')} of ${restored.length}` +
+          (count('copied') > 0 ? ` (${count('copied')} as copies)` : '')
+      );
+      setItems(await getBackupItems(selected, itemKind));
+      setChecked(new Set());
+    } catch (err) {
+      setError(errorMessage(err));
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  const handleSelect = async (name: string) => {
+    setSelected(name);
+    setPreview(null);
+    setConfirming(false);
+    setError(null);
+    setMessage(null);
+    setLoading(true);
+    try {
+      setPreview(await previewBackup(name));
+    } catch (err) {
+      setError(errorMessage(err));
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  const handleRestore = async () => {
+    if (!selected) return;
+    setLoading(true);
+    try {
+      // The backend emits data::changed, which reloads the app
+      await restoreBackup(selected);
+      onClose();
+    } catch (err) {
+      setError(errorMessage(err));
+    } finally {
+      setLoading(false);
+      setConfirming(false);
+    }
+  };
+
+  const unchanged =
+    preview &&
+    DIFF_SECTIONS.every(({ key }) => {
+      const diff = preview[key];
+      return diff.added.length + diff.removed.length + diff.changed.length === 0;
+    }) &&
+    !preview.settings_changed;
+
+  return (
+    <Modal isOpen={isOpen} onClose={onClose} title="Restore from Backup" size="lg">
+      <div className="backup-browser">
+        <ul className="backup-list">
+          {backups.length === 0 && <li className="backup-empty">No backups yet</li>}
+          {backups.map((name) => (
+            <li key={name}>
+              <button
+                className={`backup-list-item ${name === selected ? 'selected' : ''}`}
+                onClick={() => handleSelect(name)}
+              >
+                {backupLabel(name)}
+              </button>
+            </li>
+          ))}
+        </ul>
+
+        <div className="backup-preview">
+          {error && <div className="backup-error">{error}</div>}
+          {loading && <div className="spinner spinner-sm"></div>}
+          {!selected && !error && <p className="backup-empty">Select a backup to see what would change</p>}
+          {selected && (
+            <div className="backup-tabs">
+              <Button
+                variant={mode === 'changes' ? 'secondary' : 'ghost'}
+                size="sm"
+                onClick={() => setMode('changes')}
+              >
+                Changes
+              </Button>
+              <Button
+                variant={mode === 'items' ? 'secondary' : 'ghost'}
+                size="sm"
+                onClick={() => setMode('items')}
+              >
+                Items
+              </Button>
+            </div>
+          )}
+          {message && <p className="backup-meta">{message}</p>}
+          {selected && mode === 'items' && (
+            <>
+              <div className="backup-items-toolbar">
+                <select value={itemKind} onChange={(e) => setItemKind(e.target.value as BackupItemKind)}>
+                  {ITEM_KINDS.map(({ kind, label }) => (
+                    <option key={kind} value={kind}>
+                      {label}
+                    </option>
+                  ))}
+                </select>
+                <select
+                  value={onConflict}
+                  onChange={(e) => setOnConflict(e.target.value as ConflictStrategy)}
+                  title="When an item with the same id already exists"
+                >
+                  <option value="keep_both">Keep both if changed</option>
+                  <option value="replace">Replace if changed</option>
+                </select>
+              </div>
+              {items.length === 0 && <p className="backup-empty">None in this backup</p>}
+              <ul className="backup-items">
+                {items.map((item) => (
+                  <li key={item.id}>
+                    <label className="backup-item">
+                      <input
+                        type="checkbox"
+                        checked={checked.has(item.id)}
+                        disabled={item.status === 'same'}
+                        onChange={() => toggleItem(item.id)}
+                      />
+                      <span className="backup-item-title">{item.title}</span>
+                      {item.detail && <span className="backup-diff-fields">{item.detail}</span>}
+                      <span className={`backup-item-status ${item.status}`}>{STATUS_LABELS[item.status]}</span>
+                    </label>
+                  </li>
+                ))}
+              </ul>
+              <div className="backup-actions">
+                <Button size="sm" onClick={handleRestoreItems} disabled={loading || checked.size === 0}>
+                  Restore Selected ({checked.size})
+                </Button>
+              </div>
+            </>
+          )}
+          {preview && mode === 'changes' && (
+            <>
+              <p className="backup-meta">
+                {preview.verified ? 'Checksums verified' : 'Legacy backup (no checksums)'}
+                {preview.images > 0 && ` · ${preview.images} images`}
+              </p>
+              <table className="backup-counts">
+                <thead>
+                  <tr>
+                    <th></th>
+                    <th>Backup</th>
+                    <th>Current</th>
+                  </tr>
+                </thead>
+                <tbody>
+                  {COUNT_ROWS.map(({ key, label }) => (
+                    <tr key={key}>
+                      <td>{label}</td>
+                      <td>{preview.backup[key]}</td>
+                      <td>{preview.current[key]}</td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+
+              {unchanged ? (
+                <p className="backup-empty">Same as your current data</p>
+              ) : (
+                <>
+                  {DIFF_SECTIONS.map(({ key, label }) => (
+                    <DiffList key={key} label={label} diff={preview[key]} />
+                  ))}
+                  {preview.settings_changed && <p className="backup-meta">Settings differ</p>}
+                </>
+              )}
+
+              <div className="backup-actions">
+                {confirming ? (
+                  <>
+                    <Button variant="ghost" size="sm" onClick={() => setConfirming(false)}>
+                      Cancel
+                    </Button>
+                    <Button variant="danger" size="sm" onClick={handleRestore} disabled={loading}>
+                      Replace Current Data
+                    </Button>
+                  </>
+                ) : (
+                  <Button size="sm" onClick={() => setConfirming(true)} disabled={loading}>
+                    Restore
+                  </Button>
+                )}
+              </div>
+            </>
+          )}
+        </div>
+      </div>
+    </Modal>
+  );
+}
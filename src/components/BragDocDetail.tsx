@@ -1,13 +1,15 @@
-import { useState } from 'react';
-import { ask } from '@tauri-apps/plugin-dialog';
+import { useState, useEffect } from 'react';
+import { message } from '@tauri-apps/plugin-dialog';
 import { Button, Modal, ImageLightbox } from './index';
 import { EntryForm } from './EntryForm';
-import { exportBragDocToMarkdown } from '../utils/exportMarkdown';
-import type { BragDoc, BragEntry } from '../types';
+import { exportBragDoc, getBragSummary, suggestBragEntries } from '../hooks/useAppData';
+import type { BragDoc, BragEntry, BragSummary, ExportFormat, Tag } from '../types';
 import '../views/BragDocView.css';
 
 interface BragDocDetailProps {
   doc: BragDoc;
+  tags: Tag[];
+  categories: string[];
   onBack: () => void;
   onEdit: () => void;
   onAddEntry: (entry: Omit<BragEntry, 'id'>) => void;
@@ -17,6 +19,8 @@ interface BragDocDetailProps {
 
 export function BragDocDetail({
   doc,
+  tags,
+  categories,
   onBack,
   onEdit,
   onAddEntry,
@@ -28,6 +32,16 @@ export function BragDocDetail({
   const [lightboxImages, setLightboxImages] = useState<string[]>([]);
   const [lightboxIndex, setLightboxIndex] = useState(0);
   const [showLightbox, setShowLightbox] = useState(false);
+  const [suggestions, setSuggestions] = useState<BragEntry[] | null>(null);
+  const [loadingSuggestions, setLoadingSuggestions] = useState(false);
+  const [summary, setSummary] = useState<BragSummary | null>(null);
+
+  // Refetch whenever entries or the configured categories change
+  useEffect(() => {
+    getBragSummary(doc.id)
+      .then(setSummary)
+      .catch((e) => console.error('Failed to load brag summary:', e));
+  }, [doc.id, doc.entries, categories]);
 
   const openLightbox = (images: string[], index: number) => {
     setLightboxImages(images);
@@ -56,19 +70,32 @@ export function BragDocDetail({
     (a, b) => new Date(b.date).getTime() - new Date(a.date).getTime()
   );
 
-  const handleExport = async () => {
-    const markdown = exportBragDocToMarkdown(doc);
-
-    // Create a blob and download it
-    const blob = new Blob([markdown], { type: 'text/markdown' });
-    const url = URL.createObjectURL(blob);
-    const a = document.createElement('a');
-    a.href = url;
-    a.download = `${doc.title.replace(/[^a-z0-9]/gi, '-').toLowerCase()}-brag-doc.md`;
-    document.body.appendChild(a);
-    a.click();
-    document.body.removeChild(a);
-    URL.revokeObjectURL(url);
+  const handleExport = async (format: ExportFormat) => {
+    try {
+      const exported = await exportBragDoc(doc.id, format);
+      if (exported && exported.warnings.length > 0) {
+        await message(exported.warnings.join('\n'), { title: 'Exported with omissions', kind: 'warning' });
+      }
+    } catch (e) {
+      console.error('Failed to export brag doc:', e);
+    }
+  };
+
+  const handleSuggest = async () => {
+    setLoadingSuggestions(true);
+    try {
+      setSuggestions(await suggestBragEntries(doc.id));
+    } catch (e) {
+      console.error('Failed to suggest brag entries:', e);
+    } finally {
+      setLoadingSuggestions(false);
+    }
+  };
+
+  const handleAcceptSuggestion = (suggestion: BragEntry) => {
+    const { id, ...entry } = suggestion;
+    onAddEntry(entry);
+    setSuggestions((prev) => prev?.filter((s) => s.id !== id) ?? null);
   };
 
   const handleAddEntry = (entry: Omit<BragEntry, 'id'>) => {
@@ -101,9 +128,18 @@ export function BragDocDetail({
             <Button variant="ghost" size="sm" onClick={onEdit}>
               Edit Period
             </Button>
-            <Button variant="secondary" size="sm" onClick={handleExport}>
+            <Button variant="secondary" size="sm" onClick={() => handleExport('markdown')}>
               Export MD
             </Button>
+            <Button variant="secondary" size="sm" onClick={() => handleExport('html')}>
+              HTML
+            </Button>
+            <Button variant="secondary" size="sm" onClick={() => handleExport('pdf')}>
+              PDF
+            </Button>
+            <Button variant="secondary" size="sm" onClick={handleSuggest} disabled={loadingSuggestions}>
+              {loadingSuggestions ? 'Finding...' : 'Suggest'}
+            </Button>
             <Button size="sm" onClick={() => setShowAddEntry(true)}>
               + Add Entry
             </Button>
@@ -117,6 +153,16 @@ export function BragDocDetail({
           <span className="entries-count">{doc.entries.length} entries</span>
         </div>
 
+        {summary && summary.total > 0 && (
+          <div className="category-summary">
+            {summary.categories.map((group) => (
+              <span key={group.category ?? ''} className="category-summary-item">
+                {group.category ?? 'Uncategorized'} <strong>{group.count}</strong>
+              </span>
+            ))}
+          </div>
+        )}
+
         {sortedEntries.length === 0 ? (
           <div className="empty-state">
             <p>No entries yet. Add your first accomplishment!</p>
@@ -132,6 +178,23 @@ export function BragDocDetail({
                   <h3 className="entry-card-title">{entry.title}</h3>
                   <span className="entry-card-date">{formatEntryDate(entry.date)}</span>
                 </div>
+                {(entry.draft || entry.category || entry.tag_ids.length > 0) && (
+                  <div className="entry-card-labels">
+                    {entry.draft && <span className="entry-category">Draft</span>}
+                    {entry.category && <span className="entry-category">{entry.category}</span>}
+                    {tags
+                      .filter((tag) => entry.tag_ids.includes(tag.id))
+                      .map((tag) => (
+                        <span
+                          key={tag.id}
+                          className="entry-tag"
+                          style={{ '--tag-color': tag.color } as React.CSSProperties}
+                        >
+                          {tag.name}
+                        </span>
+                      ))}
+                  </div>
+                )}
                 {entry.description && (
                   <p className="entry-card-description">{entry.description}</p>
                 )}
@@ -177,12 +240,7 @@ export function BragDocDetail({
                   </button>
                   <button
                     className="entry-action-btn danger"
-                    onClick={async () => {
-                      const confirmed = await ask('Delete this entry?', { title: 'Confirm Delete', kind: 'warning' });
-                      if (confirmed) {
-                        onDeleteEntry(entry.id);
-                      }
-                    }}
+                    onClick={() => onDeleteEntry(entry.id)}
                   >
                     Delete
                   </button>
@@ -201,6 +259,8 @@ export function BragDocDetail({
         size="lg"
       >
         <EntryForm
+          tags={tags}
+          categories={categories}
           onSubmit={handleAddEntry}
           onCancel={() => setShowAddEntry(false)}
         />
@@ -216,12 +276,48 @@ export function BragDocDetail({
         {editingEntry && (
           <EntryForm
             initialData={editingEntry}
+            tags={tags}
+            categories={categories}
             onSubmit={handleUpdateEntry}
             onCancel={() => setEditingEntry(null)}
           />
         )}
       </Modal>
 
+      {/* Suggested Entries Modal */}
+      <Modal
+        isOpen={suggestions !== null}
+        onClose={() => setSuggestions(null)}
+        title="Suggested Accomplishments"
+        size="lg"
+      >
+        {suggestions && suggestions.length === 0 ? (
+          <p className="empty-state">No new merged PRs or completed tasks in this period.</p>
+        ) : (
+          <div className="entries-list">
+            {suggestions?.map((suggestion) => (
+              <div key={suggestion.id} className="entry-card">
+                <div className="entry-card-header">
+                  <h3 className="entry-card-title">{suggestion.title}</h3>
+                  <span className="entry-card-date">{formatEntryDate(suggestion.date)}</span>
+                </div>
+                {suggestion.description && (
+                  <p className="entry-card-description">{suggestion.description}</p>
+                )}
+                <div className="entry-card-actions">
+                  <button
+                    className="entry-action-btn"
+                    onClick={() => handleAcceptSuggestion(suggestion)}
+                  >
+                    Add
+                  </button>
+                </div>
+              </div>
+            ))}
+          </div>
+        )}
+      </Modal>
+
       {/* Image Lightbox */}
       <ImageLightbox
         images={lightboxImages}
@@ -9,6 +9,10 @@ interface DropdownMenuProps {
   darkMode: boolean;
   onThemeChange: (theme: Theme) => void;
   onDarkModeToggle: () => void;
+  undoLabel: string | null;
+  redoLabel: string | null;
+  onUndo: () => void;
+  onRedo: () => void;
   onQuit: () => void;
 }
 
@@ -19,6 +23,10 @@ export function DropdownMenu({
   darkMode,
   onThemeChange,
   onDarkModeToggle,
+  undoLabel,
+  redoLabel,
+  onUndo,
+  onRedo,
   onQuit,
 }: DropdownMenuProps) {
   const menuRef = useRef<HTMLDivElement>(null);
@@ -102,6 +110,31 @@ export function DropdownMenu({
 
         <div className="dropdown-divider" />
 
+        <button
+          className="dropdown-item"
+          onClick={onUndo}
+          disabled={!undoLabel}
+          role="menuitem"
+          title={undoLabel ?? undefined}
+        >
+          <span className="dropdown-item-icon">&lt;</span>
+          <span className="dropdown-item-label">{undoLabel ? `Undo ${undoLabel}` : 'Undo'}</span>
+          <span className="dropdown-item-shortcut">⌘Z</span>
+        </button>
+        <button
+          className="dropdown-item"
+          onClick={onRedo}
+          disabled={!redoLabel}
+          role="menuitem"
+          title={redoLabel ?? undefined}
+        >
+          <span className="dropdown-item-icon">&gt;</span>
+          <span className="dropdown-item-label">{redoLabel ? `Redo ${redoLabel}` : 'Redo'}</span>
+          <span className="dropdown-item-shortcut">⇧⌘Z</span>
+        </button>
+
+        <div className="dropdown-divider" />
+
         <button className="dropdown-item" onClick={() => {}} role="menuitem">
           <span className="dropdown-item-icon">i</span>
           <span>About Atulify</span>
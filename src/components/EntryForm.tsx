@@ -1,16 +1,18 @@
 import { useState } from 'react';
 import { Button } from './Button';
 import { ImageUpload } from './ImageUpload';
-import type { BragEntry } from '../types';
+import type { BragEntry, Tag } from '../types';
 import './BragDocForm.css';
 
 interface EntryFormProps {
   initialData?: BragEntry;
+  tags: Tag[];
+  categories: string[];
   onSubmit: (data: Omit<BragEntry, 'id'>) => void;
   onCancel: () => void;
 }
 
-export function EntryForm({ initialData, onSubmit, onCancel }: EntryFormProps) {
+export function EntryForm({ initialData, tags, categories, onSubmit, onCancel }: EntryFormProps) {
   const today = new Date().toISOString().split('T')[0];
 
   const [title, setTitle] = useState(initialData?.title || '');
@@ -18,6 +20,8 @@ export function EntryForm({ initialData, onSubmit, onCancel }: EntryFormProps) {
   const [date, setDate] = useState(initialData?.date || today);
   const [links, setLinks] = useState<string[]>(initialData?.links || []);
   const [images, setImages] = useState<string[]>(initialData?.images || []);
+  const [tagIds, setTagIds] = useState<string[]>(initialData?.tag_ids || []);
+  const [category, setCategory] = useState(initialData?.category || '');
   const [newLink, setNewLink] = useState('');
   const [error, setError] = useState<string | null>(null);
 
@@ -36,6 +40,9 @@ export function EntryForm({ initialData, onSubmit, onCancel }: EntryFormProps) {
       date,
       links,
       images,
+      tag_ids: tagIds,
+      category: category || null,
+      draft: false,
     });
   };
 
@@ -52,6 +59,12 @@ export function EntryForm({ initialData, onSubmit, onCancel }: EntryFormProps) {
     }
   };
 
+  const toggleTag = (tagId: string) => {
+    setTagIds((prev) =>
+      prev.includes(tagId) ? prev.filter((id) => id !== tagId) : [...prev, tagId]
+    );
+  };
+
   const handleRemoveLink = (index: number) => {
     setLinks(links.filter((_, i) => i !== index));
   };
@@ -101,6 +114,44 @@ export function EntryForm({ initialData, onSubmit, onCancel }: EntryFormProps) {
         />
       </div>
 
+      <div className="form-group">
+        <label htmlFor="entryCategory" className="form-label">Category</label>
+        <select
+          id="entryCategory"
+          value={category}
+          onChange={(e) => setCategory(e.target.value)}
+          className="form-input"
+          style={{ width: 'auto' }}
+        >
+          <option value="">Uncategorized</option>
+          {/* Keep a category that was removed from settings selectable for existing entries */}
+          {[...categories, ...(category && !categories.includes(category) ? [category] : [])].map((c) => (
+            <option key={c} value={c}>
+              {c}
+            </option>
+          ))}
+        </select>
+      </div>
+
+      {tags.length > 0 && (
+        <div className="form-group">
+          <label className="form-label">Tags</label>
+          <div className="entry-tags">
+            {tags.map((tag) => (
+              <button
+                key={tag.id}
+                type="button"
+                className={`entry-tag-btn ${tagIds.includes(tag.id) ? 'active' : ''}`}
+                style={{ '--tag-color': tag.color } as React.CSSProperties}
+                onClick={() => toggleTag(tag.id)}
+              >
+                {tag.name}
+              </button>
+            ))}
+          </div>
+        </div>
+      )}
+
       <div className="form-group">
         <label className="form-label">Links</label>
         <div className="links-input-group">
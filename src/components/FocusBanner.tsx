@@ -0,0 +1,57 @@
+import { useState, useEffect } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import { Button } from './Button';
+import { getFocusStatus, stopFocus } from '../hooks/useAppData';
+import type { FocusStatus } from '../types';
+import './FocusBanner.css';
+
+const PHASE_LABELS: Record<FocusStatus['phase'], string> = {
+  work: 'Focus',
+  short_break: 'Short break',
+  long_break: 'Long break',
+};
+
+// Shows the running focus session, if any, with a live countdown
+export function FocusBanner() {
+  const [status, setStatus] = useState<FocusStatus | null>(null);
+  const [now, setNow] = useState(() => Date.now());
+
+  useEffect(() => {
+    getFocusStatus().then(setStatus);
+    const unlisten = listen<FocusStatus | null>('focus::changed', (event) => {
+      setStatus(event.payload);
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  useEffect(() => {
+    if (!status) return;
+    const interval = setInterval(() => setNow(Date.now()), 1000);
+    return () => clearInterval(interval);
+  }, [status]);
+
+  if (!status) return null;
+
+  const remaining = Math.max(0, Math.round((new Date(status.phase_ends_at).getTime() - now) / 1000));
+  const minutes = String(Math.floor(remaining / 60)).padStart(2, '0');
+  const seconds = String(remaining % 60).padStart(2, '0');
+
+  return (
+    <div className={`focus-banner ${status.phase}`}>
+      <div className="focus-banner-info">
+        <span className="focus-banner-phase">{PHASE_LABELS[status.phase]}</span>
+        <span className="focus-banner-task">{status.task_title}</span>
+      </div>
+      <span className="focus-banner-time">
+        {minutes}:{seconds}
+      </span>
+      <span className="focus-banner-count">{status.completed_pomodoros} done</span>
+      <Button variant="ghost" size="sm" onClick={() => stopFocus()}>
+        Stop
+      </Button>
+    </div>
+  );
+}
@@ -1,5 +1,5 @@
 import { useState, useRef, useCallback, useEffect } from 'react';
-import { invoke } from '@tauri-apps/api/core';
+import { errorMessage, saveImage } from '../hooks/useAppData';
 import './ImageUpload.css';
 
 interface ImageUploadProps {
@@ -15,13 +15,6 @@ export function ImageUpload({ images, onChange, maxImages = 10 }: ImageUploadPro
   const fileInputRef = useRef<HTMLInputElement>(null);
   const dropZoneRef = useRef<HTMLDivElement>(null);
 
-  const generateFilename = (originalName: string) => {
-    const ext = originalName.split('.').pop() || 'png';
-    const timestamp = Date.now();
-    const random = Math.random().toString(36).substring(2, 8);
-    return `${timestamp}-${random}.${ext}`;
-  };
-
   const processFile = async (file: File): Promise<string | null> => {
     // Validate file type
     if (!file.type.startsWith('image/')) {
@@ -38,17 +31,14 @@ export function ImageUpload({ images, onChange, maxImages = 10 }: ImageUploadPro
     try {
       const arrayBuffer = await file.arrayBuffer();
       const uint8Array = new Uint8Array(arrayBuffer);
-      const filename = generateFilename(file.name);
 
-      const savedPath = await invoke<string>('save_image', {
-        filename,
-        data: Array.from(uint8Array),
-      });
+      // The backend names the file after its contents; this is only checked
+      const saved = await saveImage(file.name || 'image', Array.from(uint8Array));
 
-      return savedPath;
+      return saved.path;
     } catch (err) {
       console.error('Failed to save image:', err);
-      setError('Failed to save image');
+      setError(errorMessage(err));
       return null;
     }
   };
@@ -70,7 +60,8 @@ export function ImageUpload({ images, onChange, maxImages = 10 }: ImageUploadPro
 
     for (const file of filesToProcess) {
       const path = await processFile(file);
-      if (path) {
+      // The same image twice is the same file; keep one reference
+      if (path && !images.includes(path) && !newPaths.includes(path)) {
         newPaths.push(path);
       }
     }
@@ -156,19 +147,9 @@ export function ImageUpload({ images, onChange, maxImages = 10 }: ImageUploadPro
     }
   };
 
-  const handleRemoveImage = async (index: number) => {
-    const imagePath = images[index];
-    const filename = imagePath.split('/').pop();
-
-    try {
-      if (filename) {
-        await invoke('delete_image', { filename });
-      }
-    } catch (err) {
-      console.error('Failed to delete image file:', err);
-      // Continue with removal from state even if file deletion fails
-    }
-
+  // Only drops the reference. The file stays on disk, since a cancelled edit
+  // still points at it.
+  const handleRemoveImage = (index: number) => {
     onChange(images.filter((_, i) => i !== index));
   };
 
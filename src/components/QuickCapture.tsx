@@ -0,0 +1,82 @@
+import { useState, useEffect, useRef } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { getCurrentWindow } from '@tauri-apps/api/window';
+import type { AppData, CaptureResult } from '../types';
+import { errorMessage } from '../hooks/useAppData';
+import './QuickCapture.css';
+
+export function QuickCapture() {
+  const [input, setInput] = useState('');
+  const [error, setError] = useState<string | null>(null);
+  const [saving, setSaving] = useState(false);
+  const inputRef = useRef<HTMLInputElement>(null);
+
+  // Match the main window's theme
+  useEffect(() => {
+    invoke<AppData>('get_all_data').then((data) => {
+      document.documentElement.setAttribute('data-theme', data.settings.theme);
+      document.documentElement.setAttribute(
+        'data-mode',
+        data.settings.dark_mode ? 'dark' : 'light'
+      );
+    });
+  }, []);
+
+  // Reset and focus the input every time the window is shown
+  useEffect(() => {
+    const unlisten = getCurrentWindow().onFocusChanged(({ payload: focused }) => {
+      if (focused) {
+        setError(null);
+        inputRef.current?.focus();
+      } else {
+        getCurrentWindow().hide();
+      }
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  const handleSubmit = async () => {
+    if (!input.trim() || saving) return;
+
+    try {
+      setSaving(true);
+      await invoke<CaptureResult>('quick_capture', { input });
+      setInput('');
+      setError(null);
+      await getCurrentWindow().hide();
+    } catch (e) {
+      setError(errorMessage(e));
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleKeyDown = async (event: React.KeyboardEvent<HTMLInputElement>) => {
+    if (event.key === 'Enter') {
+      event.preventDefault();
+      await handleSubmit();
+    } else if (event.key === 'Escape') {
+      setInput('');
+      await getCurrentWindow().hide();
+    }
+  };
+
+  return (
+    <div className="quick-capture">
+      <input
+        ref={inputRef}
+        className="quick-capture-input"
+        value={input}
+        onChange={(e) => setInput(e.target.value)}
+        onKeyDown={handleKeyDown}
+        placeholder="Task… #tag @tomorrow !pr <url> !note"
+        disabled={saving}
+        autoFocus
+      />
+      {error && <div className="quick-capture-error">{error}</div>}
+    </div>
+  );
+}
@@ -0,0 +1,106 @@
+import { useState } from 'react';
+import { Button } from './Button';
+import { getReviewTurnaround, type ReviewTurnaround as Turnaround } from '../hooks/useGitHubStats';
+import { errorMessage } from '../hooks/useAppData';
+import './ReviewTurnaround.css';
+
+function formatDuration(seconds: number | null): string {
+  if (seconds === null) return '-';
+  const hours = seconds / 3600;
+  if (hours < 1) return `${Math.max(1, Math.round(seconds / 60))}m`;
+  if (hours < 48) return `${hours.toFixed(1)}h`;
+  return `${(hours / 24).toFixed(1)}d`;
+}
+
+/**
+ * How quickly I pick up review requests, loaded on demand since it
+ * pulls the request timeline for every PR
+ */
+export function ReviewTurnaround() {
+  const [turnaround, setTurnaround] = useState<Turnaround | null>(null);
+  const [loading, setLoading] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const load = async () => {
+    setLoading(true);
+    setError(null);
+    try {
+      setTurnaround(await getReviewTurnaround());
+    } catch (err) {
+      setError(errorMessage(err));
+    } finally {
+      setLoading(false);
+    }
+  };
+
+  return (
+    <div className="prs-section review-turnaround">
+      <div className="prs-section-header">
+        <div className="prs-section-title-row">
+          <h2 className="prs-section-title">Review Turnaround</h2>
+          {turnaround && (
+            <span className="prs-section-count">
+              median {formatDuration(turnaround.median_seconds)}, p90{' '}
+              {formatDuration(turnaround.p90_seconds)} over {turnaround.reviews} reviews
+            </span>
+          )}
+          {loading && <div className="spinner spinner-sm"></div>}
+        </div>
+        <Button variant="ghost" size="sm" onClick={load} disabled={loading}>
+          {loading ? 'Loading...' : turnaround ? 'Refresh' : 'Load'}
+        </Button>
+      </div>
+      {(turnaround || error) && (
+        <div className="prs-section-content">
+          {error && <div className="prs-error">{error}</div>}
+          {turnaround && (
+            <>
+              <table className="turnaround-table">
+                <thead>
+                  <tr>
+                    <th>Week of</th>
+                    <th>Reviews</th>
+                    <th>Median</th>
+                    <th>p90</th>
+                  </tr>
+                </thead>
+                <tbody>
+                  {turnaround.weeks.map((week) => (
+                    <tr key={week.week_start}>
+                      <td>{week.week_start}</td>
+                      <td>{week.reviews}</td>
+                      <td>{formatDuration(week.median_seconds)}</td>
+                      <td>{formatDuration(week.p90_seconds)}</td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+
+              <h3 className="turnaround-subtitle">
+                Requested, Never Reviewed ({turnaround.never_reviewed.length})
+              </h3>
+              {turnaround.never_reviewed.length === 0 ? (
+                <div className="prs-empty">None</div>
+              ) : (
+                <ul className="turnaround-unreviewed">
+                  {turnaround.never_reviewed.map((pr) => (
+                    <li key={pr.number}>
+                      <a href={pr.url} target="_blank" rel="noopener noreferrer" className="pr-card-link">
+                        #{pr.number}
+                      </a>{' '}
+                      {pr.title}
+                      <span className="turnaround-meta">
+                        {pr.state.toLowerCase()}, requested{' '}
+                        {new Date(pr.requested_at).toLocaleDateString()}
+                      </span>
+                    </li>
+                  ))}
+                </ul>
+              )}
+            </>
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
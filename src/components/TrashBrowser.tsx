@@ -0,0 +1,119 @@
+import { useEffect, useState } from 'react';
+import { Button } from './Button';
+import { Modal } from './Modal';
+import { emptyTrash, errorMessage, getTrash, purgeTrashItem, restoreTrashItem } from '../hooks/useAppData';
+import type { TrashItem, TrashKind } from '../types';
+import './TrashBrowser.css';
+
+const KIND_LABELS: Record<TrashKind, string> = {
+  task: 'Task',
+  note: 'Note',
+  brag_entry: 'Brag Entry',
+  notification: 'Reminder',
+};
+
+function formatDate(iso: string): string {
+  return new Date(iso).toLocaleDateString(undefined, { month: 'short', day: 'numeric' });
+}
+
+interface TrashBrowserProps {
+  isOpen: boolean;
+  onClose: () => void;
+}
+
+/**
+ * Deleted tasks, notes, brag entries and reminders. Restoring puts an item
+ * back where it was; deleting forever also removes images nothing else uses.
+ */
+export function TrashBrowser({ isOpen, onClose }: TrashBrowserProps) {
+  const [items, setItems] = useState<TrashItem[]>([]);
+  const [error, setError] = useState<string | null>(null);
+  const [busy, setBusy] = useState(false);
+  const [confirmingEmpty, setConfirmingEmpty] = useState(false);
+
+  const refresh = () =>
+    getTrash()
+      .then(setItems)
+      .catch((err) => setError(errorMessage(err)));
+
+  useEffect(() => {
+    if (!isOpen) return;
+    setError(null);
+    setConfirmingEmpty(false);
+    refresh();
+  }, [isOpen]);
+
+  // The backend emits data::changed after each of these, so views reload
+  const run = async (action: () => Promise<unknown>) => {
+    setBusy(true);
+    setError(null);
+    try {
+      await action();
+      await refresh();
+    } catch (err) {
+      setError(errorMessage(err));
+    } finally {
+      setBusy(false);
+      setConfirmingEmpty(false);
+    }
+  };
+
+  return (
+    <Modal isOpen={isOpen} onClose={onClose} title="Trash" size="lg">
+      {error && <div className="trash-error">{error}</div>}
+      {items.length === 0 ? (
+        <p className="trash-empty">The trash is empty</p>
+      ) : (
+        <ul className="trash-list">
+          {items.map((item) => (
+            <li key={`${item.kind}-${item.id}`} className="trash-item">
+              <span className="trash-item-kind">{KIND_LABELS[item.kind]}</span>
+              <div className="trash-item-main">
+                <div className="trash-item-title">{item.title}</div>
+                <div className="trash-item-meta">
+                  {item.context && `${item.context} · `}
+                  Deleted {formatDate(item.deleted_at)}
+                  {item.purge_at && ` · removed ${formatDate(item.purge_at)}`}
+                </div>
+              </div>
+              <Button
+                variant="ghost"
+                size="sm"
+                disabled={busy}
+                onClick={() => run(() => restoreTrashItem(item.kind, item.id))}
+              >
+                Restore
+              </Button>
+              <Button
+                variant="ghost"
+                size="sm"
+                disabled={busy}
+                onClick={() => run(() => purgeTrashItem(item.kind, item.id))}
+              >
+                Delete Forever
+              </Button>
+            </li>
+          ))}
+        </ul>
+      )}
+      {items.length > 0 && (
+        <div className="trash-actions">
+          {confirmingEmpty ? (
+            <>
+              <Button variant="ghost" size="sm" onClick={() => setConfirmingEmpty(false)}>
+                Cancel
+              </Button>
+              <Button variant="danger" size="sm" disabled={busy} onClick={() => run(emptyTrash)}>
+                Delete {items.length} Forever
+              </Button>
+            </>
+          ) : (
+            <Button variant="secondary" size="sm" onClick={() => setConfirmingEmpty(true)}>
+              Empty Trash
+            </Button>
+          )}
+        </div>
+      )}
+    </Modal>
+  );
+}
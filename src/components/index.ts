@@ -10,3 +10,8 @@ export { ImageUpload } from './ImageUpload';
 export { ImageLightbox } from './ImageLightbox';
 export { Onboarding } from './Onboarding';
 export { ProgressCircle } from './ProgressCircle';
+export { QuickCapture } from './QuickCapture';
+export { FocusBanner } from './FocusBanner';
+export { ReviewTurnaround } from './ReviewTurnaround';
+export { BackupBrowser } from './BackupBrowser';
+export { TrashBrowser } from './TrashBrowser';
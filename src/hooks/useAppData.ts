@@ -1,6 +1,36 @@
 import { invoke } from '@tauri-apps/api/core';
 import { useState, useEffect, useCallback } from 'react';
-import type { AppData } from '../types';
+import type {
+  AppData,
+  AppError,
+  BackupItem,
+  BackupItemKind,
+  BackupPreview,
+  BragEntry,
+  BragSummary,
+  CleanupResult,
+  ConflictStrategy,
+  DiagnosticsReport,
+  ExportedFile,
+  ExportFormat,
+  ExportGrouping,
+  FocusSession,
+  FocusStatus,
+  ImageScan,
+  HistoryStatus,
+  LogEntry,
+  LogLevel,
+  Notification,
+  ProductivityStats,
+  RestoredItem,
+  RunningTimer,
+  SavedImage,
+  StepResult,
+  TimeTotals,
+  TrashItem,
+  TrashKind,
+  WeeklyReview,
+} from '../types';
 
 const defaultAppData: AppData = {
   tags: [],
@@ -14,9 +44,41 @@ const defaultAppData: AppData = {
     launch_at_login: false,
     user_name: '',
     onboarding_complete: false,
+    shortcuts: {
+      toggle_window: 'Super+Shift+B',
+      quick_capture: 'Super+Shift+N',
+      open_today: '',
+      review_clipboard_pr: '',
+      task_from_clipboard: '',
+    },
+    week_start: null,
+    brag_categories: ['Impact', 'Collaboration', 'Craft', 'Leadership'],
+    review_cycle: { cycle: null, carry_over_drafts: false },
+    focus: {
+      work_minutes: 25,
+      short_break_minutes: 5,
+      long_break_minutes: 15,
+      sessions_before_long_break: 4,
+    },
+    logging: { level: 'info', modules: {} },
+    backups: { interval_minutes: 60, hourly: 24, daily: 14, weekly: 12 },
+    trash: { retention_days: 30 },
+    image_cleanup: { scheduled: false },
   },
+  focus_sessions: [],
 };
 
+export function isAppError(err: unknown): err is AppError {
+  return typeof err === 'object' && err !== null && 'code' in err && 'message' in err;
+}
+
+// User-facing text for anything a command (or other code) threw
+export function errorMessage(err: unknown): string {
+  if (isAppError(err)) return err.message;
+  if (err instanceof Error) return err.message;
+  return String(err);
+}
+
 export function useAppData() {
   const [data, setData] = useState<AppData>(defaultAppData);
   const [loading, setLoading] = useState(true);
@@ -29,7 +91,7 @@ export function useAppData() {
       const appData = await invoke<AppData>('get_all_data');
       setData(appData);
     } catch (e) {
-      setError(e instanceof Error ? e.message : String(e));
+      setError(errorMessage(e));
       console.error('Failed to load data:', e);
     } finally {
       setLoading(false);
@@ -41,7 +103,7 @@ export function useAppData() {
       await invoke('save_all_data', { data: newData });
       setData(newData);
     } catch (e) {
-      setError(e instanceof Error ? e.message : String(e));
+      setError(errorMessage(e));
       console.error('Failed to save data:', e);
       throw e;
     }
@@ -69,6 +131,7 @@ export function useAppData() {
   };
 }
 
+// Manual snapshot, kept outside the hourly/daily/weekly tiers
 export async function createBackup(): Promise<string> {
   return invoke<string>('create_backup');
 }
@@ -77,21 +140,172 @@ export async function getBackups(): Promise<string[]> {
   return invoke<string[]>('get_backups');
 }
 
+// Verifies the backup and diffs it against the current data without restoring
+export async function previewBackup(backupName: string): Promise<BackupPreview> {
+  return invoke<BackupPreview>('preview_backup', { backupName });
+}
+
+// The current data is snapshotted as a "pre-restore" backup first
 export async function restoreBackup(backupName: string): Promise<AppData> {
   return invoke<AppData>('restore_backup', { backupName });
 }
 
+export async function getBackupItems(backupName: string, kind: BackupItemKind): Promise<BackupItem[]> {
+  return invoke<BackupItem[]>('get_backup_items', { backupName, kind });
+}
+
+// Restores only the given items (plus their tags and images) into the current data
+export async function restoreBackupItems(
+  backupName: string,
+  items: { kind: BackupItemKind; id: string }[],
+  onConflict: ConflictStrategy = 'keep_both'
+): Promise<RestoredItem[]> {
+  return invoke<RestoredItem[]>('restore_backup_items', { backupName, items, onConflict });
+}
+
+// Each emits data::changed and history::changed
+export async function undo(): Promise<StepResult> {
+  return invoke<StepResult>('undo');
+}
+
+export async function redo(): Promise<StepResult> {
+  return invoke<StepResult>('redo');
+}
+
+export async function getHistoryStatus(): Promise<HistoryStatus> {
+  return invoke<HistoryStatus>('get_history_status');
+}
+
+// Soft delete; the item stays restorable until it's purged
+export async function trashItem(kind: TrashKind, id: string): Promise<void> {
+  return invoke('trash_item', { kind, id });
+}
+
+export async function getTrash(): Promise<TrashItem[]> {
+  return invoke<TrashItem[]>('get_trash');
+}
+
+export async function restoreTrashItem(kind: TrashKind, id: string): Promise<void> {
+  return invoke('restore_trash_item', { kind, id });
+}
+
+// Deletes the item and any images nothing else uses
+export async function purgeTrashItem(kind: TrashKind, id: string): Promise<void> {
+  return invoke('purge_trash_item', { kind, id });
+}
+
+export async function emptyTrash(): Promise<number> {
+  return invoke<number>('empty_trash');
+}
+
+// Images not referenced by current data, backups or undo history; deletes nothing
+export async function scanImages(): Promise<ImageScan> {
+  return invoke<ImageScan>('scan_images');
+}
+
+// Only deletes names that are still unused
+export async function deleteUnusedImages(names: string[]): Promise<CleanupResult> {
+  return invoke<CleanupResult>('delete_unused_images', { names });
+}
+
+// Rejects anything that isn't a PNG, JPEG, GIF or WebP; identical images share one file
 export async function saveImage(
   filename: string,
   data: number[]
-): Promise<string> {
-  return invoke<string>('save_image', { filename, data });
+): Promise<SavedImage> {
+  return invoke<SavedImage>('save_image', { filename, data });
 }
 
 export async function deleteImage(filename: string): Promise<void> {
   return invoke('delete_image', { filename });
 }
 
+export async function parseSchedule(expression: string): Promise<string> {
+  return invoke<string>('parse_schedule', { expression });
+}
+
+// Resolves to the written file, or null if the save dialog was cancelled
+export async function exportBragDoc(
+  docId: string,
+  format: ExportFormat,
+  grouping: ExportGrouping = 'month'
+): Promise<ExportedFile | null> {
+  return invoke<ExportedFile | null>('export_brag_doc', { docId, format, grouping });
+}
+
+// Draft entries from merged PRs and completed tasks in the doc's date range; not saved
+export async function suggestBragEntries(docId: string): Promise<BragEntry[]> {
+  return invoke<BragEntry[]>('suggest_brag_entries', { docId });
+}
+
+export async function getBragSummary(docId: string): Promise<BragSummary> {
+  return invoke<BragSummary>('get_brag_summary', { docId });
+}
+
+// Summary of the week containing `date` (YYYY-MM-DD, default today)
+export async function generateWeeklyReview(date?: string): Promise<WeeklyReview> {
+  return invoke<WeeklyReview>('generate_weekly_review', { date: date ?? null });
+}
+
+// Resolves to the new note's id
+export async function saveWeeklyReviewNote(markdown: string): Promise<string> {
+  return invoke<string>('save_weekly_review_note', { markdown });
+}
+
+export async function scheduleWeeklyReviewReminder(time?: string): Promise<Notification> {
+  return invoke<Notification>('schedule_weekly_review_reminder', { time: time ?? null });
+}
+
+// Fails if another task's timer is already running
+export async function startTimer(taskId: string): Promise<RunningTimer> {
+  return invoke<RunningTimer>('start_timer', { taskId });
+}
+
+// Resolves to the seconds added by the stopped interval
+export async function stopTimer(taskId: string): Promise<number> {
+  return invoke<number>('stop_timer', { taskId });
+}
+
+export async function getRunningTimer(): Promise<RunningTimer | null> {
+  return invoke<RunningTimer | null>('get_running_timer');
+}
+
+// Per-tag and per-day totals between two YYYY-MM-DD dates (inclusive)
+export async function getTimeTotals(start: string, end: string): Promise<TimeTotals> {
+  return invoke<TimeTotals>('get_time_totals', { start, end });
+}
+
+export async function startFocus(taskId: string): Promise<FocusStatus> {
+  return invoke<FocusStatus>('start_focus', { taskId });
+}
+
+// Resolves to the logged partial work phase, if one was under way
+export async function stopFocus(): Promise<FocusSession | null> {
+  return invoke<FocusSession | null>('stop_focus');
+}
+
+export async function getFocusStatus(): Promise<FocusStatus | null> {
+  return invoke<FocusStatus | null>('get_focus_status');
+}
+
+// Dates are YYYY-MM-DD; omitted bounds default to the last 12 weeks
+export async function getProductivityStats(
+  start?: string,
+  end?: string
+): Promise<ProductivityStats> {
+  return invoke<ProductivityStats>('get_productivity_stats', { start, end });
+}
+
+// Checks gh, storage directories, backups, notifications and shortcuts
+export async function runDiagnostics(): Promise<DiagnosticsReport> {
+  return invoke<DiagnosticsReport>('run_diagnostics');
+}
+
+// Newest first; defaults to the last 200 warnings and errors
+export async function getRecentLogs(limit?: number, level?: LogLevel): Promise<LogEntry[]> {
+  return invoke<LogEntry[]>('get_recent_logs', { limit, level });
+}
+
 export async function getAppDataPath(): Promise<string> {
   return invoke<string>('get_app_data_path');
 }
@@ -1,5 +1,6 @@
 import { useState, useCallback, useEffect, useRef } from 'react';
 import { invoke } from '@tauri-apps/api/core';
+import { errorMessage } from './useAppData';
 
 export interface GitHubStats {
   prs_merged_mtd: number;
@@ -10,8 +11,71 @@ export interface GitHubStats {
   prs_approved_prev_3_months: number;
 }
 
+export type StatsGranularity = 'week' | 'month';
+
+export interface PeriodCount {
+  start: string;
+  end: string;
+  merged: number;
+  reviewed: number;
+}
+
+export interface GitHubRangeStats {
+  start: string;
+  end: string;
+  merged: number;
+  reviewed: number;
+  granularity: StatsGranularity;
+  series: PeriodCount[];
+  synced_at: string | null;
+}
+
+/**
+ * Merged/reviewed PR counts for any range (YYYY-MM-DD, end defaults to today).
+ * Served from the on-disk cache; only new or missing history is fetched.
+ */
+export async function getGitHubStats(
+  start: string,
+  end?: string,
+  granularity: StatsGranularity = 'week',
+  forceRefresh = false
+): Promise<GitHubRangeStats> {
+  return invoke<GitHubRangeStats>('get_github_stats', { start, end, granularity, forceRefresh });
+}
+
+export interface WeekTurnaround {
+  week_start: string;
+  reviews: number;
+  median_seconds: number | null;
+  p90_seconds: number | null;
+}
+
+export interface UnreviewedPr {
+  number: number;
+  title: string;
+  url: string;
+  state: string;
+  requested_at: string;
+}
+
+export interface ReviewTurnaround {
+  start: string;
+  end: string;
+  reviews: number;
+  median_seconds: number | null;
+  p90_seconds: number | null;
+  weeks: WeekTurnaround[];
+  never_reviewed: UnreviewedPr[];
+}
+
+// Omitted bounds default to the last 12 weeks
+export async function getReviewTurnaround(start?: string, end?: string): Promise<ReviewTurnaround> {
+  return invoke<ReviewTurnaround>('get_review_turnaround', { start, end });
+}
+
 export interface GitHubStatsState {
   stats: GitHubStats | null;
+  trend: GitHubRangeStats | null;
   loading: boolean;
   error: string | null;
   lastRefresh: Date | null;
@@ -20,6 +84,7 @@ export interface GitHubStatsState {
 export function useGitHubStats() {
   const [state, setState] = useState<GitHubStatsState>({
     stats: null,
+    trend: null,
     loading: false,
     error: null,
     lastRefresh: null,
@@ -27,12 +92,22 @@ export function useGitHubStats() {
 
   const initialFetch = useRef(false);
 
-  const fetchStats = useCallback(async () => {
+  const fetchStats = useCallback(async (forceRefresh = false) => {
     setState((prev) => ({ ...prev, loading: true, error: null }));
     try {
       const stats = await invoke<GitHubStats>('fetch_github_stats');
+      // Falls within the 90 days just synced, so normally served from the cache
+      const start = new Date();
+      start.setDate(start.getDate() - 7 * 12 + 1);
+      const trend = await getGitHubStats(
+        start.toISOString().split('T')[0],
+        undefined,
+        'week',
+        forceRefresh
+      );
       setState({
         stats,
+        trend,
         loading: false,
         error: null,
         lastRefresh: new Date(),
@@ -41,7 +116,7 @@ export function useGitHubStats() {
       setState((prev) => ({
         ...prev,
         loading: false,
-        error: String(err),
+        error: errorMessage(err),
       }));
     }
   }, []);
@@ -4,6 +4,7 @@ import {
   requestPermission,
   sendNotification,
 } from '@tauri-apps/plugin-notification';
+import { generateWeeklyReview, saveWeeklyReviewNote } from './useAppData';
 import type { Notification } from '../types';
 
 type PermissionState = 'granted' | 'denied' | 'default';
@@ -146,6 +147,15 @@ export function useNotifications({
           return;
         }
 
+        if (notification.action === 'weekly_review') {
+          try {
+            const review = await generateWeeklyReview();
+            await saveWeeklyReviewNote(review.markdown);
+          } catch (e) {
+            console.error('Failed to save weekly review:', e);
+          }
+        }
+
         const hasPermission = await checkPermission();
         if (hasPermission) {
           sendNotification({
@@ -180,9 +190,9 @@ export function useNotifications({
     // Clear existing schedules
     clearAllScheduled();
 
-    // Schedule each enabled notification
+    // Schedule each enabled notification that isn't in the trash
     notifications
-      .filter((n) => n.enabled)
+      .filter((n) => n.enabled && !n.deleted_at)
       .forEach((notification) => {
         scheduleNotification(notification);
       });
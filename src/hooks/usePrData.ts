@@ -1,5 +1,6 @@
 import { useState, useCallback, useEffect, useRef } from 'react';
 import { invoke } from '@tauri-apps/api/core';
+import { errorMessage } from './useAppData';
 
 export interface PrApproval {
   username: string;
@@ -91,7 +92,7 @@ export function usePrData() {
     } catch (err) {
       setPrReviews(prev => ({
         ...prev,
-        highPriority: { ...prev.highPriority, error: String(err), loading: false }
+        highPriority: { ...prev.highPriority, error: errorMessage(err), loading: false }
       }));
     }
   }, [fetchCategory]);
@@ -110,7 +111,7 @@ export function usePrData() {
     } catch (err) {
       setPrReviews(prev => ({
         ...prev,
-        mediumPriority: { ...prev.mediumPriority, error: String(err), loading: false }
+        mediumPriority: { ...prev.mediumPriority, error: errorMessage(err), loading: false }
       }));
     }
   }, [fetchCategory]);
@@ -129,7 +130,7 @@ export function usePrData() {
     } catch (err) {
       setPrReviews(prev => ({
         ...prev,
-        lowPriority: { ...prev.lowPriority, error: String(err), loading: false }
+        lowPriority: { ...prev.lowPriority, error: errorMessage(err), loading: false }
       }));
     }
   }, [fetchCategory]);
@@ -158,7 +159,7 @@ export function usePrData() {
     } catch (err) {
       setMyPrs(prev => ({
         ...prev,
-        approved: { ...prev.approved, error: String(err), loading: false }
+        approved: { ...prev.approved, error: errorMessage(err), loading: false }
       }));
     }
   }, [fetchCategory]);
@@ -177,7 +178,7 @@ export function usePrData() {
     } catch (err) {
       setMyPrs(prev => ({
         ...prev,
-        changesRequested: { ...prev.changesRequested, error: String(err), loading: false }
+        changesRequested: { ...prev.changesRequested, error: errorMessage(err), loading: false }
       }));
     }
   }, [fetchCategory]);
@@ -196,7 +197,7 @@ export function usePrData() {
     } catch (err) {
       setMyPrs(prev => ({
         ...prev,
-        needsReview: { ...prev.needsReview, error: String(err), loading: false }
+        needsReview: { ...prev.needsReview, error: errorMessage(err), loading: false }
       }));
     }
   }, [fetchCategory]);
@@ -1,9 +1,14 @@
 import React from "react";
 import ReactDOM from "react-dom/client";
+import { getCurrentWindow } from "@tauri-apps/api/window";
 import App from "./App";
+import { QuickCapture } from "./components";
+import "./styles/global.css";
+
+const isQuickCapture = getCurrentWindow().label === "quick-capture";
 
 ReactDOM.createRoot(document.getElementById("root") as HTMLElement).render(
   <React.StrictMode>
-    <App />
+    {isQuickCapture ? <QuickCapture /> : <App />}
   </React.StrictMode>,
 );
@@ -49,6 +49,90 @@ export interface Task {
   task_type: TaskType;
   task_url: string | null; // URL for flag_rollout and pr_review types
   pr_approvals?: PrApproval[]; // Approvals for pr_review tasks
+  time_intervals: TimeInterval[];
+  deleted_at?: string | null; // set while in the trash
+}
+
+export interface TimeInterval {
+  started_at: string; // ISO datetime
+  ended_at: string | null; // null while the timer is running
+}
+
+export interface FocusSession {
+  id: string;
+  task_id: string;
+  started_at: string; // ISO datetime
+  ended_at: string;
+  planned_minutes: number;
+  completed: boolean; // false if stopped before the work phase ended
+}
+
+export type FocusPhase = 'work' | 'short_break' | 'long_break';
+
+export interface FocusStatus {
+  task_id: string;
+  task_title: string;
+  phase: FocusPhase;
+  phase_started_at: string;
+  phase_ends_at: string;
+  remaining_seconds: number;
+  completed_pomodoros: number;
+}
+
+export interface RunningTimer {
+  task_id: string;
+  title: string;
+  started_at: string;
+}
+
+export interface TagTotal {
+  tag_id: string | null; // null for untagged tasks
+  name: string;
+  seconds: number;
+}
+
+export interface DayTotal {
+  date: string; // YYYY-MM-DD
+  seconds: number;
+}
+
+export interface TimeTotals {
+  start: string;
+  end: string;
+  total_seconds: number;
+  by_tag: TagTotal[];
+  by_day: DayTotal[];
+}
+
+export interface DayCount {
+  date: string;
+  count: number;
+}
+
+export interface WeekCount {
+  week_start: string;
+  count: number;
+}
+
+export interface BragDocCount {
+  doc_id: string;
+  title: string;
+  start_date: string;
+  end_date: string;
+  count: number;
+}
+
+export interface ProductivityStats {
+  start: string;
+  end: string;
+  tasks_completed: number;
+  completed_per_day: DayCount[];
+  completed_per_week: WeekCount[];
+  average_days_to_complete: number | null;
+  carry_over_rate: number | null;
+  backlog_size: WeekCount[];
+  notes_per_week: WeekCount[];
+  brag_entries_per_doc: BragDocCount[];
 }
 
 export interface Note {
@@ -59,6 +143,7 @@ export interface Note {
   tag_ids: string[];
   linked_task_ids: string[];
   images: string[];
+  deleted_at?: string | null;
 }
 
 export interface BragEntry {
@@ -68,6 +153,11 @@ export interface BragEntry {
   date: string; // YYYY-MM-DD
   images: string[];
   links: string[];
+  tag_ids: string[];
+  category: string | null;
+  draft: boolean; // suggested or unfinished; carried over on rollover
+  created_at?: string | null; // when it was written; `date` is when the work happened
+  deleted_at?: string | null;
 }
 
 export interface BragDoc {
@@ -78,6 +168,18 @@ export interface BragDoc {
   entries: BragEntry[];
 }
 
+export interface CategorySummary {
+  category: string | null; // null for uncategorized entries
+  count: number;
+  entries: BragEntry[];
+}
+
+export interface BragSummary {
+  doc_id: string;
+  total: number;
+  categories: CategorySummary[];
+}
+
 export type ScheduleType = 'one_off' | 'daily_weekdays' | 'weekly';
 
 export interface Notification {
@@ -89,16 +191,249 @@ export interface Notification {
   date: string | null; // YYYY-MM-DD for one_off
   day_of_week: number | null; // 0-6 for weekly (Sunday = 0)
   enabled: boolean;
+  action: NotificationAction | null;
+  deleted_at?: string | null;
+}
+
+// Work done when a notification fires, in addition to showing it
+export type NotificationAction = 'weekly_review';
+
+export interface ReviewItem {
+  id: string;
+  title: string;
+}
+
+export interface ReviewPr {
+  number: number;
+  title: string;
+  url: string;
+}
+
+export interface WeeklyReview {
+  week_start: string; // YYYY-MM-DD
+  week_end: string;
+  tasks_completed: ReviewItem[];
+  tasks_carried_over: ReviewItem[];
+  notes_written: ReviewItem[];
+  prs_merged: ReviewPr[];
+  prs_reviewed: ReviewPr[];
+  brag_entries_added: ReviewItem[];
+  github_error: string | null;
+  markdown: string;
 }
 
 export type Theme = 'grove' | 'obsidian' | 'miami_nights';
 
+// Global shortcut accelerators, e.g. "CmdOrCtrl+Shift+N"; empty means unbound
+export interface ShortcutSettings {
+  toggle_window: string;
+  quick_capture: string;
+  open_today: string;
+  review_clipboard_pr: string;
+  task_from_clipboard: string;
+}
+
+export type ShortcutAction = keyof ShortcutSettings;
+
+export interface ShortcutStatus {
+  action: ShortcutAction;
+  accelerator: string;
+  registered: boolean;
+  error: string | null;
+}
+
+export type WeekStart = 'monday' | 'sunday' | 'saturday';
+
+export interface CycleStart {
+  month: number; // 1-12
+  day: number;
+}
+
+export type ReviewCycle =
+  | { kind: 'quarterly' }
+  | { kind: 'half_yearly' }
+  | { kind: 'custom'; starts: CycleStart[] };
+
+export interface ReviewCycleSettings {
+  cycle: ReviewCycle | null; // null: brag docs are created by hand
+  carry_over_drafts: boolean;
+}
+
+export interface FocusSettings {
+  work_minutes: number;
+  short_break_minutes: number;
+  long_break_minutes: number;
+  sessions_before_long_break: number;
+}
+
+// Newest backup of each hour/day/ISO week is kept for this many periods
+export interface BackupSettings {
+  interval_minutes: number;
+  hourly: number;
+  daily: number;
+  weekly: number;
+}
+
+export interface ItemCounts {
+  tasks: number;
+  notes: number;
+  brag_docs: number;
+  brag_entries: number;
+  tags: number;
+  notifications: number;
+  focus_sessions: number;
+}
+
+export interface ItemChange {
+  id: string;
+  title: string;
+  fields: string[]; // empty for added and removed items
+}
+
+// Relative to the current data: `added` items would come back on restore,
+// `removed` ones would be lost
+export interface CollectionDiff {
+  added: ItemChange[];
+  removed: ItemChange[];
+  changed: ItemChange[];
+}
+
+export interface BackupPreview {
+  name: string;
+  created_at: string;
+  reason: string | null; // 'manual', 'pre-restore', ...; null for scheduled backups
+  verified: boolean; // false for legacy .json backups without a manifest
+  images: number;
+  backup: ItemCounts;
+  current: ItemCounts;
+  tasks: CollectionDiff;
+  notes: CollectionDiff;
+  brag_docs: CollectionDiff;
+  brag_entries: CollectionDiff;
+  settings_changed: boolean;
+}
+
+export type BackupItemKind = 'task' | 'note' | 'brag_doc' | 'brag_entry';
+
+export interface BackupItem {
+  kind: BackupItemKind;
+  id: string;
+  title: string;
+  detail: string | null; // date, or the brag doc an entry belongs to
+  status: 'missing' | 'same' | 'different'; // compared with the current data
+}
+
+// keep_both adds the backup's version under a new id when the id is taken
+export type ConflictStrategy = 'keep_both' | 'replace';
+
+export interface RestoredItem {
+  kind: BackupItemKind;
+  id: string;
+  restored_id: string; // differs from id when copied
+  outcome: 'added' | 'replaced' | 'copied' | 'unchanged';
+}
+
+export interface TrashSettings {
+  retention_days: number; // 0 keeps trashed items until purged by hand
+}
+
+// Daily deletion of images nothing refers to; off means only on request
+export interface ImageCleanupSettings {
+  scheduled: boolean;
+}
+
+export interface UnusedImage {
+  name: string;
+  size: number; // bytes
+  modified: string | null;
+}
+
+export interface ImageScan {
+  unused: UnusedImage[]; // largest first
+  unused_bytes: number;
+  in_use: number;
+  backups_scanned: number;
+  unreadable_backups: string[]; // images only these refer to show as unused
+}
+
+// An uploaded image, stored under the hash of its contents
+export interface SavedImage {
+  name: string; // <sha256>.<ext>
+  path: string; // what notes and brag entries store
+  existing: boolean; // an identical image was already saved
+}
+
+export interface CleanupResult {
+  deleted: number;
+  freed_bytes: number;
+}
+
+export type TrashKind = 'task' | 'note' | 'brag_entry' | 'notification';
+
+export interface TrashItem {
+  kind: TrashKind;
+  id: string;
+  title: string;
+  context: string | null; // the brag doc an entry belongs to
+  deleted_at: string;
+  purge_at: string | null; // null when automatic purging is off
+}
+
+// Sent with the history::changed event; null when there's nothing to undo/redo
+export interface HistoryStatus {
+  undo_label: string | null; // e.g. 'Delete task "Write docs"'
+  redo_label: string | null;
+}
+
+export interface StepResult {
+  skipped: string[]; // entities edited again since, left as they are, e.g. 'task "Write docs"'
+  status: HistoryStatus;
+}
+
+export type LogLevel = 'error' | 'warn' | 'info' | 'debug';
+
+export interface LogSettings {
+  level: LogLevel;
+  modules: Record<string, LogLevel>; // e.g. { commands: 'debug', storage: 'warn' }
+}
+
+export interface LogEntry {
+  timestamp: string;
+  level: LogLevel;
+  module: string;
+  message: string;
+}
+
+export type CheckStatus = 'ok' | 'warning' | 'error';
+
+export interface DiagnosticCheck {
+  id: string; // e.g. 'gh_auth', 'images_dir', 'rate_limit_graphql'
+  label: string;
+  status: CheckStatus;
+  detail: string;
+}
+
+export interface DiagnosticsReport {
+  status: CheckStatus; // worst of all checks
+  checks: DiagnosticCheck[];
+  ran_at: string;
+}
+
 export interface Settings {
   theme: Theme;
   dark_mode: boolean;
   launch_at_login: boolean;
   user_name: string;
   onboarding_complete: boolean;
+  shortcuts: ShortcutSettings;
+  week_start: WeekStart | null; // null follows the system locale
+  brag_categories: string[];
+  review_cycle: ReviewCycleSettings;
+  focus: FocusSettings;
+  logging: LogSettings;
+  backups: BackupSettings;
+  trash: TrashSettings;
+  image_cleanup: ImageCleanupSettings;
 }
 
 export interface AppData {
@@ -108,6 +443,33 @@ export interface AppData {
   brag_docs: BragDoc[];
   notifications: Notification[];
   settings: Settings;
+  focus_sessions: FocusSession[];
+}
+
+export type CaptureKind = 'task' | 'note';
+
+export interface CaptureResult {
+  kind: CaptureKind;
+  id: string;
+  title: string;
+}
+
+export type UrlKind = 'pull_request' | 'issue' | 'link';
+
+export interface UrlTaskResult {
+  kind: UrlKind;
+  task_id: string;
+  title: string;
+  created: boolean; // false when a task for the URL already existed
+}
+
+export type ExportFormat = 'markdown' | 'html' | 'pdf';
+
+export type ExportGrouping = 'month' | 'tag';
+
+export interface ExportedFile {
+  path: string;
+  warnings: string[]; // e.g. images or characters a PDF couldn't include
 }
 
 // View types
@@ -121,3 +483,22 @@ export type ViewType =
   | 'brag-doc'
   | 'notifications'
   | 'settings';
+
+// Rejection value of every command
+export type AppErrorCode =
+  | 'gh_not_installed'
+  | 'gh_not_authenticated'
+  | 'rate_limited'
+  | 'network_unavailable'
+  | 'gh_failed'
+  | 'data_corrupt'
+  | 'backup_invalid'
+  | 'storage'
+  | 'not_found'
+  | 'invalid_input'
+  | 'internal';
+
+export interface AppError {
+  code: AppErrorCode;
+  message: string;
+}
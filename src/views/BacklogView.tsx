@@ -1,7 +1,7 @@
 import { useState, useMemo } from 'react';
 import { invoke } from '@tauri-apps/api/core';
-import { ask } from '@tauri-apps/plugin-dialog';
 import { Button, Modal } from '../components';
+import { trashItem } from '../hooks/useAppData';
 import type { AppData, Task, Tag, ResourceLink, LinkType, TaskType, PrApproval } from '../types';
 import './Views.css';
 import './BacklogView.css';
@@ -28,10 +28,10 @@ export function BacklogView({ data, onDataChange }: BacklogViewProps) {
   const [taskType, setTaskType] = useState<TaskType>('regular');
   const [taskUrl, setTaskUrl] = useState('');
 
-  // Get backlog tasks (no scheduled date, not completed, not archived)
+  // Get backlog tasks (no scheduled date, not completed, not archived, not trashed)
   const backlogTasks = useMemo(() => {
     let tasks = data.tasks.filter(
-      (task) => !task.scheduled_date && !task.completed && !task.archived
+      (task) => !task.scheduled_date && !task.completed && !task.archived && !task.deleted_at
     );
 
     // Apply search
@@ -164,6 +164,7 @@ export function BacklogView({ data, onDataChange }: BacklogViewProps) {
         task_type: taskType,
         task_url: urlValue,
         pr_approvals: prApprovals,
+        time_intervals: [],
       };
 
       onDataChange({
@@ -213,12 +214,8 @@ export function BacklogView({ data, onDataChange }: BacklogViewProps) {
 
   // Delete task
   const handleDeleteTask = async (taskId: string) => {
-    const confirmed = await ask('Delete this task?', { title: 'Confirm Delete', kind: 'warning' });
-    if (!confirmed) return;
-    onDataChange({
-      ...data,
-      tasks: data.tasks.filter((task) => task.id !== taskId),
-    });
+    // Restorable from Settings → Trash; the backend emits data::changed
+    await trashItem('task', taskId);
     if (editingTask?.id === taskId) {
       setEditingTask(null);
     }
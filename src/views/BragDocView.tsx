@@ -1,10 +1,11 @@
-import { useState } from 'react';
+import { useEffect, useState } from 'react';
 import { Button, Modal } from '../components';
-import type { AppData, BragDoc, BragEntry } from '../types';
+import type { AppData, BragDoc, BragEntry, ProductivityStats } from '../types';
 import { BragDocCard } from '../components/BragDocCard';
 import { BragDocDetail } from '../components/BragDocDetail';
 import { BragDocForm } from '../components/BragDocForm';
 import { useGitHubStats } from '../hooks/useGitHubStats';
+import { getProductivityStats, trashItem } from '../hooks/useAppData';
 import './Views.css';
 import './BragDocView.css';
 
@@ -18,12 +19,31 @@ export function BragDocView({ data, onDataChange }: BragDocViewProps) {
   const [showCreateModal, setShowCreateModal] = useState(false);
   const [editingDoc, setEditingDoc] = useState<BragDoc | null>(null);
 
-  const { stats, loading: statsLoading, fetchStats } = useGitHubStats();
+  const { stats, trend, loading: statsLoading, fetchStats } = useGitHubStats();
+  const [productivity, setProductivity] = useState<ProductivityStats | null>(null);
 
-  const selectedDoc = data.brag_docs.find((doc) => doc.id === selectedDocId);
+  // Computed locally, so refresh whenever the data changes
+  useEffect(() => {
+    getProductivityStats()
+      .then(setProductivity)
+      .catch((e) => console.error('Failed to load productivity stats:', e));
+  }, [data]);
+
+  const trendMax = Math.max(1, ...(trend?.series ?? []).map((p) => Math.max(p.merged, p.reviewed)));
+
+  const thisWeek = (series: { count: number }[] | undefined) =>
+    series && series.length > 0 ? series[series.length - 1].count : 0;
+
+  // Trashed entries stay in the data until purged but aren't shown
+  const visibleDocs = data.brag_docs.map((doc) => ({
+    ...doc,
+    entries: doc.entries.filter((e) => !e.deleted_at),
+  }));
+
+  const selectedDoc = visibleDocs.find((doc) => doc.id === selectedDocId);
 
   // Sort brag docs by end_date descending (most recent first)
-  const sortedDocs = [...data.brag_docs].sort(
+  const sortedDocs = [...visibleDocs].sort(
     (a, b) => new Date(b.end_date).getTime() - new Date(a.end_date).getTime()
   );
 
@@ -72,6 +92,7 @@ export function BragDocView({ data, onDataChange }: BragDocViewProps) {
     const newEntry: BragEntry = {
       ...entry,
       id: crypto.randomUUID(),
+      created_at: new Date().toISOString(),
     };
     onDataChange({
       ...data,
@@ -100,16 +121,9 @@ export function BragDocView({ data, onDataChange }: BragDocViewProps) {
     });
   };
 
-  const handleDeleteEntry = (entryId: string) => {
-    if (!selectedDocId) return;
-    onDataChange({
-      ...data,
-      brag_docs: data.brag_docs.map((doc) =>
-        doc.id === selectedDocId
-          ? { ...doc, entries: doc.entries.filter((e) => e.id !== entryId) }
-          : doc
-      ),
-    });
+  const handleDeleteEntry = async (entryId: string) => {
+    // Restorable from Settings → Trash; the backend emits data::changed
+    await trashItem('brag_entry', entryId);
   };
 
   // If viewing a specific doc, show the detail view
@@ -117,6 +131,8 @@ export function BragDocView({ data, onDataChange }: BragDocViewProps) {
     return (
       <BragDocDetail
         doc={selectedDoc}
+        tags={data.tags}
+        categories={data.settings.brag_categories}
         onBack={() => setSelectedDocId(null)}
         onEdit={() => setEditingDoc(selectedDoc)}
         onAddEntry={handleAddEntry}
@@ -146,7 +162,7 @@ export function BragDocView({ data, onDataChange }: BragDocViewProps) {
             <Button
               variant="ghost"
               size="sm"
-              onClick={fetchStats}
+              onClick={() => fetchStats(true)}
               disabled={statsLoading}
             >
               {statsLoading ? 'Loading...' : 'Refresh'}
@@ -196,8 +212,86 @@ export function BragDocView({ data, onDataChange }: BragDocViewProps) {
               </div>
             </div>
           )}
+          {trend && trend.series.length > 0 && (
+            <div className="github-trend">
+              <h3 className="stats-card-title">Last 12 Weeks</h3>
+              <div className="github-trend-bars">
+                {trend.series.map((period) => (
+                  <div
+                    key={period.start}
+                    className="github-trend-week"
+                    title={`Week of ${period.start}: ${period.merged} merged, ${period.reviewed} reviewed`}
+                  >
+                    <div
+                      className="github-trend-bar merged"
+                      style={{ height: `${(period.merged / trendMax) * 100}%` }}
+                    />
+                    <div
+                      className="github-trend-bar reviewed"
+                      style={{ height: `${(period.reviewed / trendMax) * 100}%` }}
+                    />
+                  </div>
+                ))}
+              </div>
+              <div className="github-trend-legend">
+                <span className="github-trend-key merged">Merged</span>
+                <span className="github-trend-key reviewed">Reviewed</span>
+              </div>
+            </div>
+          )}
         </div>
 
+        {/* Local Productivity Stats */}
+        {productivity && (
+          <div className="github-stats-section">
+            <div className="github-stats-header">
+              <h2 className="github-stats-title">Productivity</h2>
+            </div>
+            <div className="github-stats-grid">
+              <div className="github-stats-card">
+                <h3 className="stats-card-title">Last 12 Weeks</h3>
+                <div className="stats-card-rows">
+                  <div className="stats-row">
+                    <span className="stats-label">Tasks Completed</span>
+                    <span className="stats-value">{productivity.tasks_completed}</span>
+                  </div>
+                  <div className="stats-row">
+                    <span className="stats-label">Avg. Days to Complete</span>
+                    <span className="stats-value">
+                      {productivity.average_days_to_complete?.toFixed(1) ?? '-'}
+                    </span>
+                  </div>
+                  <div className="stats-row">
+                    <span className="stats-label">Carry-over Rate</span>
+                    <span className="stats-value">
+                      {productivity.carry_over_rate === null
+                        ? '-'
+                        : `${Math.round(productivity.carry_over_rate * 100)}%`}
+                    </span>
+                  </div>
+                </div>
+              </div>
+              <div className="github-stats-card">
+                <h3 className="stats-card-title">This Week</h3>
+                <div className="stats-card-rows">
+                  <div className="stats-row">
+                    <span className="stats-label">Tasks Completed</span>
+                    <span className="stats-value">{thisWeek(productivity.completed_per_week)}</span>
+                  </div>
+                  <div className="stats-row">
+                    <span className="stats-label">Notes Written</span>
+                    <span className="stats-value">{thisWeek(productivity.notes_per_week)}</span>
+                  </div>
+                  <div className="stats-row">
+                    <span className="stats-label">Open Tasks</span>
+                    <span className="stats-value">{thisWeek(productivity.backlog_size)}</span>
+                  </div>
+                </div>
+              </div>
+            </div>
+          </div>
+        )}
+
         {needsNewDoc && sortedDocs.length > 0 && (
           <div className="brag-doc-alert">
             <p>Your current brag doc period has ended. Start a new one?</p>
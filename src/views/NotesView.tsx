@@ -1,7 +1,7 @@
 import React, { useState, useEffect, useMemo } from 'react';
-import { ask } from '@tauri-apps/plugin-dialog';
-import { Button, ImageUpload, ImageLightbox } from '../components';
-import type { AppData, Note, Tag } from '../types';
+import { Button, ImageUpload, ImageLightbox, Modal } from '../components';
+import { generateWeeklyReview, saveWeeklyReviewNote, trashItem } from '../hooks/useAppData';
+import type { AppData, Note, Tag, WeeklyReview } from '../types';
 import './Views.css';
 import './NotesView.css';
 
@@ -22,6 +22,10 @@ export function NotesView({ data, onDataChange }: NotesViewProps) {
   const [editTagIds, setEditTagIds] = useState<string[]>([]);
   const [editImages, setEditImages] = useState<string[]>([]);
 
+  // Weekly review state
+  const [weeklyReview, setWeeklyReview] = useState<WeeklyReview | null>(null);
+  const [loadingReview, setLoadingReview] = useState(false);
+
   // Lightbox state
   const [lightboxImages, setLightboxImages] = useState<string[]>([]);
   const [lightboxIndex, setLightboxIndex] = useState(0);
@@ -33,7 +37,7 @@ export function NotesView({ data, onDataChange }: NotesViewProps) {
 
   // Filter and sort notes
   const filteredNotes = useMemo(() => {
-    let notes = [...data.notes];
+    let notes = data.notes.filter((note) => !note.deleted_at);
 
     // Filter by search query
     if (searchQuery.trim()) {
@@ -66,6 +70,28 @@ export function NotesView({ data, onDataChange }: NotesViewProps) {
     setIsEditing(false);
   };
 
+  const handleOpenWeeklyReview = async () => {
+    setLoadingReview(true);
+    try {
+      setWeeklyReview(await generateWeeklyReview());
+    } catch (e) {
+      console.error('Failed to generate weekly review:', e);
+    } finally {
+      setLoadingReview(false);
+    }
+  };
+
+  // The backend emits data::changed after saving, which reloads the note list
+  const handleSaveWeeklyReview = async () => {
+    if (!weeklyReview) return;
+    try {
+      await saveWeeklyReviewNote(weeklyReview.markdown);
+      setWeeklyReview(null);
+    } catch (e) {
+      console.error('Failed to save weekly review:', e);
+    }
+  };
+
   // Start editing a note
   const handleStartEdit = (note: Note) => {
     setSelectedNote(note);
@@ -130,13 +156,8 @@ export function NotesView({ data, onDataChange }: NotesViewProps) {
 
   // Delete note
   const handleDeleteNote = async (noteId: string) => {
-    const confirmed = await ask('Delete this note?', { title: 'Confirm Delete', kind: 'warning' });
-    if (!confirmed) return;
-
-    onDataChange({
-      ...data,
-      notes: data.notes.filter((note) => note.id !== noteId),
-    });
+    // Restorable from Settings → Trash, images included
+    await trashItem('note', noteId);
 
     if (selectedNote?.id === noteId) {
       setSelectedNote(null);
@@ -248,7 +269,12 @@ export function NotesView({ data, onDataChange }: NotesViewProps) {
             <h1 className="view-title">Notes</h1>
             <p className="view-subtitle">Quick capture with Markdown support</p>
           </div>
-          <Button onClick={handleStartCreate}>+ New Note</Button>
+          <div className="view-header-actions">
+            <Button variant="secondary" onClick={handleOpenWeeklyReview} disabled={loadingReview}>
+              {loadingReview ? 'Generating...' : 'Weekly Review'}
+            </Button>
+            <Button onClick={handleStartCreate}>+ New Note</Button>
+          </div>
         </div>
       </div>
 
@@ -471,6 +497,31 @@ export function NotesView({ data, onDataChange }: NotesViewProps) {
         </div>
       </div>
 
+      {/* Weekly Review */}
+      <Modal
+        isOpen={weeklyReview !== null}
+        onClose={() => setWeeklyReview(null)}
+        title="Weekly Review"
+        size="lg"
+      >
+        {weeklyReview && (
+          <>
+            <div className="note-viewer-content markdown-content">
+              <MarkdownRenderer content={weeklyReview.markdown} />
+            </div>
+            <div className="form-actions">
+              <Button
+                variant="ghost"
+                onClick={() => navigator.clipboard.writeText(weeklyReview.markdown)}
+              >
+                Copy
+              </Button>
+              <Button onClick={handleSaveWeeklyReview}>Save as Note</Button>
+            </div>
+          </>
+        )}
+      </Modal>
+
       {/* Lightbox */}
       <ImageLightbox
         images={lightboxImages}
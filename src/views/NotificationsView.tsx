@@ -1,6 +1,6 @@
 import { useState } from 'react';
-import { ask } from '@tauri-apps/plugin-dialog';
 import { Button, Modal } from '../components';
+import { scheduleWeeklyReviewReminder, trashItem } from '../hooks/useAppData';
 import type { AppData, Notification, ScheduleType } from '../types';
 import './Views.css';
 import './NotificationsView.css';
@@ -32,6 +32,8 @@ export function NotificationsView({ data, onDataChange }: NotificationsViewProps
   const [date, setDate] = useState('');
   const [dayOfWeek, setDayOfWeek] = useState<number>(1); // Monday
 
+  const notifications = data.notifications.filter((n) => !n.deleted_at);
+
   // Reset form
   const resetForm = () => {
     setTitle('');
@@ -91,6 +93,7 @@ export function NotificationsView({ data, onDataChange }: NotificationsViewProps
         date: scheduleType === 'one_off' ? date : null,
         day_of_week: scheduleType === 'weekly' ? dayOfWeek : null,
         enabled: true,
+        action: null,
       };
 
       onDataChange({
@@ -103,6 +106,15 @@ export function NotificationsView({ data, onDataChange }: NotificationsViewProps
     resetForm();
   };
 
+  // The backend saves the reminder and emits data::changed, which reloads data
+  const handleScheduleWeeklyReview = async () => {
+    try {
+      await scheduleWeeklyReviewReminder();
+    } catch (e) {
+      console.error('Failed to schedule weekly review reminder:', e);
+    }
+  };
+
   // Toggle enabled
   const handleToggleEnabled = (notificationId: string) => {
     onDataChange({
@@ -115,12 +127,8 @@ export function NotificationsView({ data, onDataChange }: NotificationsViewProps
 
   // Delete notification
   const handleDelete = async (notificationId: string) => {
-    const confirmed = await ask('Delete this notification?', { title: 'Confirm Delete', kind: 'warning' });
-    if (!confirmed) return;
-    onDataChange({
-      ...data,
-      notifications: data.notifications.filter((n) => n.id !== notificationId),
-    });
+    // Restorable from Settings → Trash; the backend emits data::changed
+    await trashItem('notification', notificationId);
     if (editingNotification?.id === notificationId) {
       setEditingNotification(null);
     }
@@ -170,12 +178,19 @@ export function NotificationsView({ data, onDataChange }: NotificationsViewProps
             <h1 className="view-title">Notifications</h1>
             <p className="view-subtitle">Schedule reminders and alerts</p>
           </div>
-          <Button onClick={handleOpenAdd}>+ Add Reminder</Button>
+          <div className="view-header-actions">
+            {!notifications.some((n) => n.action === 'weekly_review') && (
+              <Button variant="secondary" onClick={handleScheduleWeeklyReview}>
+                Friday Review
+              </Button>
+            )}
+            <Button onClick={handleOpenAdd}>+ Add Reminder</Button>
+          </div>
         </div>
       </div>
 
       <div className="view-content">
-        {data.notifications.length === 0 ? (
+        {notifications.length === 0 ? (
           <div className="empty-state">
             <p>No reminders set</p>
             <Button
@@ -189,7 +204,7 @@ export function NotificationsView({ data, onDataChange }: NotificationsViewProps
           </div>
         ) : (
           <div className="notifications-list">
-            {data.notifications.map((notification) => (
+            {notifications.map((notification) => (
               <div
                 key={notification.id}
                 className={`notification-card ${!notification.enabled ? 'disabled' : ''} ${isPastOneOff(notification) ? 'past' : ''}`}
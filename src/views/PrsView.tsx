@@ -1,7 +1,7 @@
 import { useState, useEffect } from 'react';
 import { invoke } from '@tauri-apps/api/core';
 import { listen } from '@tauri-apps/api/event';
-import { Button, ProgressCircle } from '../components';
+import { Button, ProgressCircle, ReviewTurnaround } from '../components';
 import type { usePrData, GitHubPr } from '../hooks/usePrData';
 import './Views.css';
 import './PrsView.css';
@@ -290,6 +290,8 @@ export function PrsView({ prData }: PrsViewProps) {
           fetchLowPriority,
           'priority-low'
         )}
+
+        <ReviewTurnaround />
       </div>
     </div>
   );
@@ -1,13 +1,214 @@
-import { Button } from '../components';
-import type { AppData, Theme } from '../types';
+import { useState, useEffect } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { BackupBrowser, Button, Modal, TrashBrowser } from '../components';
+import {
+  createBackup,
+  deleteUnusedImages,
+  errorMessage,
+  getRecentLogs,
+  runDiagnostics,
+  scanImages,
+} from '../hooks/useAppData';
+import type {
+  AppData,
+  BackupSettings,
+  CycleStart,
+  DiagnosticsReport,
+  FocusSettings,
+  ImageScan,
+  LogEntry,
+  LogLevel,
+  ReviewCycle,
+  Theme,
+  ShortcutSettings,
+  ShortcutStatus,
+} from '../types';
 import './Views.css';
 
+function formatBytes(bytes: number): string {
+  if (bytes < 1024) return `${bytes} B`;
+  if (bytes < 1024 * 1024) return `${(bytes / 1024).toFixed(1)} KB`;
+  return `${(bytes / (1024 * 1024)).toFixed(1)} MB`;
+}
+
+const FOCUS_FIELDS: { key: keyof FocusSettings; label: string; description: string }[] = [
+  { key: 'work_minutes', label: 'Focus Length', description: 'Minutes per work session' },
+  { key: 'short_break_minutes', label: 'Short Break', description: 'Minutes between sessions' },
+  { key: 'long_break_minutes', label: 'Long Break', description: 'Minutes after a full round' },
+  { key: 'sessions_before_long_break', label: 'Round Length', description: 'Sessions before a long break' },
+];
+
+const BACKUP_FIELDS: { key: keyof BackupSettings; label: string; description: string; min: number }[] = [
+  { key: 'interval_minutes', label: 'Backup Interval', description: 'Minutes between automatic backups', min: 1 },
+  { key: 'hourly', label: 'Hourly Backups', description: 'Hours to keep a backup for', min: 0 },
+  { key: 'daily', label: 'Daily Backups', description: 'Days to keep a backup for', min: 0 },
+  { key: 'weekly', label: 'Weekly Backups', description: 'Weeks to keep a backup for', min: 0 },
+];
+
+const SHORTCUT_LABELS: { key: keyof ShortcutSettings; label: string }[] = [
+  { key: 'toggle_window', label: 'Toggle Window' },
+  { key: 'quick_capture', label: 'Quick Capture' },
+  { key: 'open_today', label: 'Open Today' },
+  { key: 'review_clipboard_pr', label: 'Review Clipboard PR' },
+  { key: 'task_from_clipboard', label: 'Task from Clipboard' },
+];
+
+// Custom cycle starts are edited as "MM-DD, MM-DD"
+const formatCycleStarts = (starts: CycleStart[]) =>
+  starts
+    .map((s) => `${String(s.month).padStart(2, '0')}-${String(s.day).padStart(2, '0')}`)
+    .join(', ');
+
+const parseCycleStarts = (value: string): CycleStart[] =>
+  value
+    .split(',')
+    .map((part) => part.trim().match(/^(\d{1,2})-(\d{1,2})$/))
+    .filter((m): m is RegExpMatchArray => m !== null)
+    .map((m) => ({ month: Number(m[1]), day: Number(m[2]) }))
+    .filter((s) => s.month >= 1 && s.month <= 12 && s.day >= 1 && s.day <= 31);
+
+const LOG_LEVELS: LogLevel[] = ['error', 'warn', 'info', 'debug'];
+
+// Per-module log levels are edited as "module=level, module=level"
+const formatLogModules = (modules: Record<string, LogLevel>) =>
+  Object.entries(modules)
+    .map(([module, level]) => `${module}=${level}`)
+    .join(', ');
+
+const parseLogModules = (value: string): Record<string, LogLevel> =>
+  Object.fromEntries(
+    value
+      .split(',')
+      .map((part) => part.split('=').map((s) => s.trim()))
+      .filter(
+        (pair): pair is [string, LogLevel] =>
+          pair.length === 2 && pair[0] !== '' && LOG_LEVELS.includes(pair[1] as LogLevel)
+      )
+  );
+
 interface SettingsViewProps {
   data: AppData;
   onDataChange: (data: AppData) => void;
 }
 
 export function SettingsView({ data, onDataChange }: SettingsViewProps) {
+  const [shortcuts, setShortcuts] = useState<ShortcutSettings>(data.settings.shortcuts);
+  const [shortcutStatus, setShortcutStatus] = useState<ShortcutStatus[]>([]);
+  const [bragCategories, setBragCategories] = useState(data.settings.brag_categories.join(', '));
+  const reviewCycle = data.settings.review_cycle;
+  const [customStarts, setCustomStarts] = useState(
+    reviewCycle.cycle?.kind === 'custom' ? formatCycleStarts(reviewCycle.cycle.starts) : '01-01, 07-01'
+  );
+
+  const logging = data.settings.logging;
+  const [logModules, setLogModules] = useState(formatLogModules(logging.modules));
+  const [showLogs, setShowLogs] = useState(false);
+  const [logLevel, setLogLevel] = useState<LogLevel>('warn');
+  const [logs, setLogs] = useState<LogEntry[]>([]);
+  const [logsError, setLogsError] = useState<string | null>(null);
+
+  const [backupMessage, setBackupMessage] = useState<string | null>(null);
+  const [showBackups, setShowBackups] = useState(false);
+  const [showTrash, setShowTrash] = useState(false);
+
+  const [imageScan, setImageScan] = useState<ImageScan | null>(null);
+  const [imageMessage, setImageMessage] = useState<string | null>(null);
+  const [imagesBusy, setImagesBusy] = useState(false);
+
+  const handleScanImages = async () => {
+    setImagesBusy(true);
+    setImageMessage(null);
+    try {
+      setImageScan(await scanImages());
+    } catch (err) {
+      setImageMessage(errorMessage(err));
+    } finally {
+      setImagesBusy(false);
+    }
+  };
+
+  const handleDeleteImages = async () => {
+    if (!imageScan) return;
+    setImagesBusy(true);
+    try {
+      const result = await deleteUnusedImages(imageScan.unused.map((image) => image.name));
+      setImageMessage(`Deleted ${result.deleted} images, freeing ${formatBytes(result.freed_bytes)}`);
+      setImageScan(null);
+    } catch (err) {
+      setImageMessage(errorMessage(err));
+    } finally {
+      setImagesBusy(false);
+    }
+  };
+
+  const imageScanSummary = (scan: ImageScan) => {
+    const found =
+      scan.unused.length === 0
+        ? `No unused images; ${scan.in_use} in use`
+        : `${scan.unused.length} unused (${formatBytes(scan.unused_bytes)}), ${scan.in_use} in use`;
+    const unreadable = scan.unreadable_backups.length;
+    return unreadable > 0 ? `${found}. ${unreadable} backups couldn't be read` : found;
+  };
+
+  const handleCreateBackup = async () => {
+    try {
+      setBackupMessage(`Saved ${await createBackup()}`);
+    } catch (err) {
+      setBackupMessage(errorMessage(err));
+    }
+  };
+
+  const [diagnostics, setDiagnostics] = useState<DiagnosticsReport | null>(null);
+  const [diagnosticsRunning, setDiagnosticsRunning] = useState(false);
+  const [diagnosticsError, setDiagnosticsError] = useState<string | null>(null);
+
+  const handleRunDiagnostics = async () => {
+    setDiagnosticsRunning(true);
+    setDiagnosticsError(null);
+    try {
+      setDiagnostics(await runDiagnostics());
+    } catch (err) {
+      setDiagnosticsError(errorMessage(err));
+    } finally {
+      setDiagnosticsRunning(false);
+    }
+  };
+
+  useEffect(() => {
+    setLogModules(formatLogModules(data.settings.logging.modules));
+  }, [data.settings.logging.modules]);
+
+  useEffect(() => {
+    if (!showLogs) return;
+    getRecentLogs(200, logLevel)
+      .then((entries) => {
+        setLogs(entries);
+        setLogsError(null);
+      })
+      .catch((err) => setLogsError(errorMessage(err)));
+  }, [showLogs, logLevel]);
+
+  useEffect(() => {
+    setShortcuts(data.settings.shortcuts);
+  }, [data.settings.shortcuts]);
+
+  useEffect(() => {
+    setBragCategories(data.settings.brag_categories.join(', '));
+  }, [data.settings.brag_categories]);
+
+  // Registration results arrive asynchronously after settings are saved
+  useEffect(() => {
+    invoke<ShortcutStatus[]>('get_shortcut_status').then(setShortcutStatus);
+    const unlisten = listen<ShortcutStatus[]>('shortcuts::status', (event) => {
+      setShortcutStatus(event.payload);
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
   const updateSettings = (updates: Partial<AppData['settings']>) => {
     onDataChange({
       ...data,
@@ -18,6 +219,52 @@ export function SettingsView({ data, onDataChange }: SettingsViewProps) {
     });
   };
 
+  const commitShortcuts = () => {
+    if (JSON.stringify(shortcuts) !== JSON.stringify(data.settings.shortcuts)) {
+      updateSettings({ shortcuts });
+    }
+  };
+
+  const commitBragCategories = () => {
+    const categories = bragCategories
+      .split(',')
+      .map((c) => c.trim())
+      .filter((c, i, all) => c && all.indexOf(c) === i);
+    if (JSON.stringify(categories) !== JSON.stringify(data.settings.brag_categories)) {
+      updateSettings({ brag_categories: categories });
+    }
+  };
+
+  const updateReviewCycle = (updates: Partial<AppData['settings']['review_cycle']>) => {
+    updateSettings({ review_cycle: { ...reviewCycle, ...updates } });
+  };
+
+  const handleCycleKindChange = (kind: string) => {
+    let cycle: ReviewCycle | null = null;
+    if (kind === 'quarterly' || kind === 'half_yearly') {
+      cycle = { kind };
+    } else if (kind === 'custom') {
+      cycle = { kind, starts: parseCycleStarts(customStarts) };
+    }
+    updateReviewCycle({ cycle });
+  };
+
+  const commitCustomStarts = () => {
+    const starts = parseCycleStarts(customStarts);
+    if (starts.length > 0) {
+      updateReviewCycle({ cycle: { kind: 'custom', starts } });
+    }
+  };
+
+  const commitLogModules = () => {
+    const modules = parseLogModules(logModules);
+    if (JSON.stringify(modules) !== JSON.stringify(logging.modules)) {
+      updateSettings({ logging: { ...logging, modules } });
+    } else {
+      setLogModules(formatLogModules(logging.modules));
+    }
+  };
+
   return (
     <div className="view">
       <div className="view-header">
@@ -107,19 +354,339 @@ export function SettingsView({ data, onDataChange }: SettingsViewProps) {
           </div>
         </div>
 
+        <div className="settings-section">
+          <h2 className="section-title">Shortcuts</h2>
+          {SHORTCUT_LABELS.map(({ key, label }) => {
+            const status = shortcutStatus.find((s) => s.action === key);
+            return (
+              <div className="settings-item" key={key}>
+                <div>
+                  <div className="settings-item-label">{label}</div>
+                  <div className="settings-item-description">
+                    {status?.error ?? 'e.g. CmdOrCtrl+Shift+K, blank to disable'}
+                  </div>
+                </div>
+                <input
+                  type="text"
+                  value={shortcuts[key]}
+                  onChange={(e) => setShortcuts({ ...shortcuts, [key]: e.target.value })}
+                  onBlur={commitShortcuts}
+                  placeholder="Not set"
+                  style={{ width: '150px' }}
+                />
+              </div>
+            );
+          })}
+        </div>
+
+        <div className="settings-section">
+          <h2 className="section-title">Brag Doc</h2>
+          <div className="settings-item">
+            <div>
+              <div className="settings-item-label">Categories</div>
+              <div className="settings-item-description">
+                Comma-separated, in the order they're summarized
+              </div>
+            </div>
+            <input
+              type="text"
+              value={bragCategories}
+              onChange={(e) => setBragCategories(e.target.value)}
+              onBlur={commitBragCategories}
+              placeholder="Impact, Collaboration"
+              style={{ width: '240px' }}
+            />
+          </div>
+          <div className="settings-item">
+            <div>
+              <div className="settings-item-label">Review Cycle</div>
+              <div className="settings-item-description">
+                Create the next brag doc automatically at startup
+              </div>
+            </div>
+            <select
+              value={reviewCycle.cycle?.kind ?? 'off'}
+              onChange={(e) => handleCycleKindChange(e.target.value)}
+              style={{ width: '140px' }}
+            >
+              <option value="off">Off</option>
+              <option value="quarterly">Quarterly</option>
+              <option value="half_yearly">Half-yearly</option>
+              <option value="custom">Custom</option>
+            </select>
+          </div>
+          {reviewCycle.cycle?.kind === 'custom' && (
+            <div className="settings-item">
+              <div>
+                <div className="settings-item-label">Period Starts</div>
+                <div className="settings-item-description">
+                  Month-day each period starts, e.g. 04-01, 10-01
+                </div>
+              </div>
+              <input
+                type="text"
+                value={customStarts}
+                onChange={(e) => setCustomStarts(e.target.value)}
+                onBlur={commitCustomStarts}
+                placeholder="04-01, 10-01"
+                style={{ width: '240px' }}
+              />
+            </div>
+          )}
+          {reviewCycle.cycle && (
+            <div className="settings-item">
+              <div>
+                <div className="settings-item-label">Carry Over Drafts</div>
+                <div className="settings-item-description">
+                  Move draft entries into the new brag doc
+                </div>
+              </div>
+              <Button
+                variant={reviewCycle.carry_over_drafts ? 'primary' : 'secondary'}
+                size="sm"
+                onClick={() =>
+                  updateReviewCycle({ carry_over_drafts: !reviewCycle.carry_over_drafts })
+                }
+              >
+                {reviewCycle.carry_over_drafts ? 'On' : 'Off'}
+              </Button>
+            </div>
+          )}
+        </div>
+
+        <div className="settings-section">
+          <h2 className="section-title">Focus Sessions</h2>
+          {FOCUS_FIELDS.map(({ key, label, description }) => (
+            <div className="settings-item" key={key}>
+              <div>
+                <div className="settings-item-label">{label}</div>
+                <div className="settings-item-description">{description}</div>
+              </div>
+              <input
+                type="number"
+                min={1}
+                value={data.settings.focus[key]}
+                onChange={(e) => {
+                  const value = Number(e.target.value);
+                  if (value >= 1) {
+                    updateSettings({ focus: { ...data.settings.focus, [key]: value } });
+                  }
+                }}
+                style={{ width: '80px' }}
+              />
+            </div>
+          ))}
+        </div>
+
         <div className="settings-section">
           <h2 className="section-title">Data</h2>
           <div className="settings-item">
             <div>
               <div className="settings-item-label">Backup</div>
               <div className="settings-item-description">
-                Create a manual backup of your data
+                {backupMessage ?? 'Create a manual backup of your data'}
               </div>
             </div>
-            <Button variant="secondary" size="sm">
+            <Button variant="secondary" size="sm" onClick={handleCreateBackup}>
               Create Backup
             </Button>
           </div>
+          <div className="settings-item">
+            <div>
+              <div className="settings-item-label">Restore</div>
+              <div className="settings-item-description">
+                Preview a backup's changes before restoring it
+              </div>
+            </div>
+            <Button variant="secondary" size="sm" onClick={() => setShowBackups(true)}>
+              Browse Backups
+            </Button>
+          </div>
+          {BACKUP_FIELDS.map(({ key, label, description, min }) => (
+            <div className="settings-item" key={key}>
+              <div>
+                <div className="settings-item-label">{label}</div>
+                <div className="settings-item-description">{description}</div>
+              </div>
+              <input
+                type="number"
+                min={min}
+                value={data.settings.backups[key]}
+                onChange={(e) => {
+                  const value = Number(e.target.value);
+                  if (Number.isInteger(value) && value >= min) {
+                    updateSettings({ backups: { ...data.settings.backups, [key]: value } });
+                  }
+                }}
+                style={{ width: '80px' }}
+              />
+            </div>
+          ))}
+          <div className="settings-item">
+            <div>
+              <div className="settings-item-label">Trash</div>
+              <div className="settings-item-description">
+                Restore deleted tasks, notes, brag entries and reminders
+              </div>
+            </div>
+            <Button variant="secondary" size="sm" onClick={() => setShowTrash(true)}>
+              View Trash
+            </Button>
+          </div>
+          <div className="settings-item">
+            <div>
+              <div className="settings-item-label">Keep Deleted Items</div>
+              <div className="settings-item-description">
+                Days before trashed items and their images are removed; 0 keeps them
+              </div>
+            </div>
+            <input
+              type="number"
+              min={0}
+              value={data.settings.trash.retention_days}
+              onChange={(e) => {
+                const value = Number(e.target.value);
+                if (Number.isInteger(value) && value >= 0) {
+                  updateSettings({ trash: { retention_days: value } });
+                }
+              }}
+              style={{ width: '80px' }}
+            />
+          </div>
+          <div className="settings-item">
+            <div>
+              <div className="settings-item-label">Unused Images</div>
+              <div className="settings-item-description">
+                {imageMessage ??
+                  (imageScan
+                    ? imageScanSummary(imageScan)
+                    : 'Find images no note, brag entry, backup or undo step refers to')}
+              </div>
+            </div>
+            {imageScan && imageScan.unused.length > 0 ? (
+              <div className="settings-item-actions">
+                <Button variant="ghost" size="sm" onClick={() => setImageScan(null)}>
+                  Cancel
+                </Button>
+                <Button variant="danger" size="sm" onClick={handleDeleteImages} disabled={imagesBusy}>
+                  Delete {imageScan.unused.length}
+                </Button>
+              </div>
+            ) : (
+              <Button variant="secondary" size="sm" onClick={handleScanImages} disabled={imagesBusy}>
+                {imagesBusy ? 'Scanning...' : 'Scan'}
+              </Button>
+            )}
+          </div>
+          <div className="settings-item">
+            <div>
+              <div className="settings-item-label">Clean Up Daily</div>
+              <div className="settings-item-description">
+                Delete unused images once a day without asking
+              </div>
+            </div>
+            <Button
+              variant={data.settings.image_cleanup.scheduled ? 'primary' : 'secondary'}
+              size="sm"
+              onClick={() =>
+                updateSettings({
+                  image_cleanup: { scheduled: !data.settings.image_cleanup.scheduled },
+                })
+              }
+            >
+              {data.settings.image_cleanup.scheduled ? 'Enabled' : 'Disabled'}
+            </Button>
+          </div>
+        </div>
+
+        <div className="settings-section">
+          <h2 className="section-title">Logging</h2>
+          <div className="settings-item">
+            <div>
+              <div className="settings-item-label">Log Level</div>
+              <div className="settings-item-description">
+                Least severe messages written to the log file
+              </div>
+            </div>
+            <select
+              value={logging.level}
+              onChange={(e) =>
+                updateSettings({ logging: { ...logging, level: e.target.value as LogLevel } })
+              }
+              style={{ width: '140px' }}
+            >
+              {LOG_LEVELS.map((level) => (
+                <option key={level} value={level}>
+                  {level[0].toUpperCase() + level.slice(1)}
+                </option>
+              ))}
+            </select>
+          </div>
+          <div className="settings-item">
+            <div>
+              <div className="settings-item-label">Module Levels</div>
+              <div className="settings-item-description">
+                Overrides per module, e.g. commands=debug, storage=warn
+              </div>
+            </div>
+            <input
+              type="text"
+              value={logModules}
+              onChange={(e) => setLogModules(e.target.value)}
+              onBlur={commitLogModules}
+              placeholder="github=debug"
+              style={{ width: '240px' }}
+            />
+          </div>
+          <div className="settings-item">
+            <div>
+              <div className="settings-item-label">Recent Logs</div>
+              <div className="settings-item-description">
+                Errors and messages from the app's log file
+              </div>
+            </div>
+            <Button variant="secondary" size="sm" onClick={() => setShowLogs(true)}>
+              View Logs
+            </Button>
+          </div>
+        </div>
+
+        <div className="settings-section">
+          <h2 className="section-title">Health</h2>
+          <div className="settings-item">
+            <div>
+              <div className="settings-item-label">Diagnostics</div>
+              <div className="settings-item-description">
+                {diagnostics
+                  ? `Last run ${new Date(diagnostics.ran_at).toLocaleTimeString()}`
+                  : 'Check GitHub CLI, storage, backups, notifications and shortcuts'}
+              </div>
+            </div>
+            <Button
+              variant="secondary"
+              size="sm"
+              onClick={handleRunDiagnostics}
+              disabled={diagnosticsRunning}
+            >
+              {diagnosticsRunning ? 'Running...' : 'Run Diagnostics'}
+            </Button>
+          </div>
+          {diagnosticsError && (
+            <div className="settings-item">
+              <div className="settings-item-description">{diagnosticsError}</div>
+            </div>
+          )}
+          {diagnostics?.checks.map((check) => (
+            <div className={`settings-item diagnostic-${check.status}`} key={check.id}>
+              <div>
+                <div className="settings-item-label">{check.label}</div>
+                <div className="settings-item-description">{check.detail}</div>
+              </div>
+              <span className={`diagnostic-status diagnostic-status-${check.status}`}>
+                {check.status === 'ok' ? 'OK' : check.status === 'warning' ? 'Warning' : 'Error'}
+              </span>
+            </div>
+          ))}
         </div>
 
         <div className="settings-section">
@@ -134,6 +701,39 @@ export function SettingsView({ data, onDataChange }: SettingsViewProps) {
           </div>
         </div>
       </div>
+
+      <BackupBrowser isOpen={showBackups} onClose={() => setShowBackups(false)} />
+      <TrashBrowser isOpen={showTrash} onClose={() => setShowTrash(false)} />
+
+      <Modal isOpen={showLogs} onClose={() => setShowLogs(false)} title="Recent Logs" size="lg">
+        <div className="settings-item">
+          <div className="settings-item-label">Show</div>
+          <select
+            value={logLevel}
+            onChange={(e) => setLogLevel(e.target.value as LogLevel)}
+            style={{ width: '140px' }}
+          >
+            <option value="error">Errors</option>
+            <option value="warn">Warnings and errors</option>
+            <option value="info">Info and above</option>
+            <option value="debug">Everything</option>
+          </select>
+        </div>
+        {logsError && <p className="settings-item-description">{logsError}</p>}
+        {!logsError && logs.length === 0 && (
+          <p className="settings-item-description">Nothing logged at this level.</p>
+        )}
+        <div className="log-entries">
+          {logs.map((entry, i) => (
+            <div key={i} className={`log-entry log-${entry.level}`}>
+              <span className="log-meta">
+                {entry.timestamp} {entry.level.toUpperCase()} {entry.module}
+              </span>
+              <pre className="log-message">{entry.message}</pre>
+            </div>
+          ))}
+        </div>
+      </Modal>
     </div>
   );
 }
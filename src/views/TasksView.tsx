@@ -1,7 +1,7 @@
 import { useState, useMemo } from 'react';
 import { invoke } from '@tauri-apps/api/core';
-import { ask } from '@tauri-apps/plugin-dialog';
-import { Button, Modal } from '../components';
+import { Button, FocusBanner, Modal } from '../components';
+import { startFocus, startTimer, stopTimer, trashItem } from '../hooks/useAppData';
 import type { AppData, Task, Tag, ResourceLink, LinkType, TaskType, PrApproval } from '../types';
 import './Views.css';
 import './TasksView.css';
@@ -31,7 +31,7 @@ export function TasksView({ data, onDataChange }: TasksViewProps) {
 
   // Get tasks for selected date
   const { activeTasks, archivedTasks } = useMemo(() => {
-    let tasks = data.tasks.filter((task) => task.scheduled_date === selectedDate);
+    let tasks = data.tasks.filter((task) => task.scheduled_date === selectedDate && !task.deleted_at);
 
     // Apply tag filter
     if (filterTagIds.length > 0) {
@@ -199,6 +199,7 @@ export function TasksView({ data, onDataChange }: TasksViewProps) {
         task_type: taskType,
         task_url: urlValue,
         pr_approvals: prApprovals,
+        time_intervals: [],
       };
 
       onDataChange({
@@ -254,12 +255,8 @@ export function TasksView({ data, onDataChange }: TasksViewProps) {
 
   // Delete task
   const handleDeleteTask = async (taskId: string) => {
-    const confirmed = await ask('Delete this task?', { title: 'Confirm Delete', kind: 'warning' });
-    if (!confirmed) return;
-    onDataChange({
-      ...data,
-      tasks: data.tasks.filter((task) => task.id !== taskId),
-    });
+    // Restorable from Settings → Trash; the backend emits data::changed
+    await trashItem('task', taskId);
     if (editingTask?.id === taskId) {
       setEditingTask(null);
     }
@@ -364,6 +361,8 @@ export function TasksView({ data, onDataChange }: TasksViewProps) {
       )}
 
       <div className="view-content">
+        <FocusBanner />
+
         {/* Add Task Button */}
         <div className="tasks-add-row">
           <Button onClick={handleOpenAddTask}>+ Add Task</Button>
@@ -599,6 +598,19 @@ export function TasksView({ data, onDataChange }: TasksViewProps) {
   );
 }
 
+// Tracked time so far, e.g. "1h 05m" or "12m"
+function formatTrackedTime(task: Task): string | null {
+  const seconds = task.time_intervals.reduce((total, interval) => {
+    const end = interval.ended_at ? new Date(interval.ended_at) : new Date();
+    return total + Math.max(0, end.getTime() - new Date(interval.started_at).getTime()) / 1000;
+  }, 0);
+  if (seconds < 60) return null;
+
+  const hours = Math.floor(seconds / 3600);
+  const minutes = Math.floor((seconds % 3600) / 60);
+  return hours > 0 ? `${hours}h ${String(minutes).padStart(2, '0')}m` : `${minutes}m`;
+}
+
 // Task Card Component
 interface TaskCardProps {
   task: Task;
@@ -627,6 +639,29 @@ function TaskCard({
 }: TaskCardProps) {
   const taskTags = tags.filter((tag) => task.tag_ids.includes(tag.id));
   const taskType = task.task_type || 'regular';
+  const timerRunning = task.time_intervals.some((interval) => interval.ended_at === null);
+  const trackedTime = formatTrackedTime(task);
+
+  // The backend saves the interval and emits data::changed, which reloads data
+  const handleToggleTimer = async () => {
+    try {
+      if (timerRunning) {
+        await stopTimer(task.id);
+      } else {
+        await startTimer(task.id);
+      }
+    } catch (err) {
+      console.error('Failed to toggle timer:', err);
+    }
+  };
+
+  const handleFocus = async () => {
+    try {
+      await startFocus(task.id);
+    } catch (err) {
+      console.error('Failed to start focus session:', err);
+    }
+  };
 
   // Handle Claude button click for PR Review tasks
   const handleCodeReview = async () => {
@@ -680,6 +715,12 @@ function TaskCard({
           <span className={`task-card-title ${task.completed ? 'completed' : ''}`}>
             {task.title}
           </span>
+          {(trackedTime || timerRunning) && (
+            <span className={`task-card-time ${timerRunning ? 'running' : ''}`}>
+              {timerRunning ? '● ' : ''}
+              {trackedTime ?? '<1m'}
+            </span>
+          )}
         </div>
         {taskTags.length > 0 && (
           <div className="task-card-tags">
@@ -765,6 +806,14 @@ function TaskCard({
         {!isArchived && onEdit && (
           <button className="task-action-btn" onClick={onEdit}>Edit</button>
         )}
+        {!isArchived && !task.completed && (
+          <button className="task-action-btn" onClick={handleToggleTimer}>
+            {timerRunning ? 'Stop' : 'Start'}
+          </button>
+        )}
+        {!isArchived && !task.completed && (
+          <button className="task-action-btn" onClick={handleFocus}>Focus</button>
+        )}
         {!isArchived && onMoveToBacklog && !task.completed && (
           <button className="task-action-btn" onClick={onMoveToBacklog}>Backlog</button>
         )}
@@ -84,7 +84,8 @@ export function TodayView({ data, onDataChange, onNavigate, prData }: TodayViewP
     (task) =>
       task.scheduled_date === todayStr &&
       !task.completed &&
-      !task.archived
+      !task.archived &&
+      !task.deleted_at
   );
 
   // Get carry-over tasks (scheduled for past dates, not completed, not archived)
@@ -93,11 +94,13 @@ export function TodayView({ data, onDataChange, onNavigate, prData }: TodayViewP
       task.scheduled_date &&
       task.scheduled_date < todayStr &&
       !task.completed &&
-      !task.archived
+      !task.archived &&
+      !task.deleted_at
   );
 
   // Get recent notes (last 5, sorted by updated_at descending)
-  const recentNotes = [...data.notes]
+  const recentNotes = data.notes
+    .filter((note) => !note.deleted_at)
     .sort((a, b) => new Date(b.updated_at).getTime() - new Date(a.updated_at).getTime())
     .slice(0, 5);
 
@@ -134,6 +137,7 @@ export function TodayView({ data, onDataChange, onNavigate, prData }: TodayViewP
       archived: false,
       task_type: 'regular',
       task_url: null,
+      time_intervals: [],
     };
 
     onDataChange({
@@ -248,7 +252,8 @@ export function TodayView({ data, onDataChange, onNavigate, prData }: TodayViewP
   const completedToday = data.tasks.filter(
     (task) =>
       task.completed_at &&
-      task.completed_at.split('T')[0] === todayStr
+      task.completed_at.split('T')[0] === todayStr &&
+      !task.deleted_at
   ).length;
 
   const totalTodayTasks = todaysTasks.length + completedToday;